@@ -0,0 +1,341 @@
+use quote::format_ident;
+use syn::meta::ParseNestedMeta;
+use syn::{DeriveInput, Expr, Field, Ident, LitBool, LitStr, Path, Token, Type, TypePath, Visibility};
+
+// Как сеттеры и build() принимают билдер
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum Pattern {
+    // &mut self -> &mut Self
+    #[default]
+    Mutable,
+    // self -> Self, build(self) забирает поля без клонирования
+    Owned,
+    // &self -> Self, сеттер возвращает изменённую копию билдера
+    Immutable,
+}
+
+// Ограничение группы полей: #[builder(group = "auth", exactly_one)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum GroupKind {
+    // задано ровно одно поле группы
+    ExactlyOne,
+    // задано не больше одного поля группы
+    AtMostOne,
+}
+
+// Форма сеттера: #[builder(setter(into, strip_option = false))].
+// На уровне поля переопределяет значения, заданные для всей структуры
+#[derive(Default)]
+pub struct SetterAttrs {
+    // сеттер принимает impl Into<T>
+    pub into: Option<bool>,
+    // сеттер Option-поля принимает T, а не Option<T>
+    pub strip_option: Option<bool>,
+}
+
+// `flag` или `flag = true/false`
+fn parse_flag(meta: &ParseNestedMeta) -> syn::Result<bool> {
+    if meta.input.peek(Token![=]) {
+        let value: LitBool = meta.value()?.parse()?;
+        Ok(value.value)
+    } else {
+        Ok(true)
+    }
+}
+
+fn parse_setter_attrs(meta: &ParseNestedMeta, setter: &mut SetterAttrs) -> syn::Result<()> {
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("into") {
+            setter.into = Some(parse_flag(&meta)?);
+            Ok(())
+        } else if meta.path.is_ident("strip_option") {
+            setter.strip_option = Some(parse_flag(&meta)?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `into` or `strip_option`"))
+        }
+    })
+}
+
+// `validate = "path::to::fn"`
+fn parse_validate(meta: &ParseNestedMeta) -> syn::Result<Path> {
+    let path: LitStr = meta.value()?.parse()?;
+    path.parse()
+}
+
+// Опции уровня структуры: #[builder(...)] над самой структурой
+pub struct StructAttrs {
+    // сеттеры обязательных полей меняют тип билдера, build() доступен только
+    // когда все обязательные поля заданы
+    pub typestate: bool,
+    // незаданные поля берутся из Default самой структуры
+    pub default: bool,
+    pub pattern: Pattern,
+    pub setter: SetterAttrs,
+    // проверка собранной структуры: fn(&T) -> Result<(), E>, E: Display
+    pub validate: Option<Path>,
+    // имя билдера, по умолчанию {Name}Builder
+    pub name: Ident,
+    // видимость билдера, его методов и ошибки, по умолчанию как у структуры
+    pub vis: Visibility,
+    // имя метода сборки, по умолчанию build
+    pub build_fn: Ident,
+    // build() возвращает Result, даже когда ошибиться ему нечем
+    pub fallible: bool,
+    // рядом с билдером генерируется {Name}Patch для частичных обновлений
+    pub patch: bool,
+    // const fn сеттеры и build() для сборки в const-контексте
+    pub is_const: bool,
+    // дополнительные derive для билдера
+    pub derives: Vec<Path>,
+}
+
+pub fn parse_struct_attrs(input: &DeriveInput) -> syn::Result<StructAttrs> {
+    let mut result = StructAttrs {
+        typestate: false,
+        default: false,
+        pattern: Pattern::default(),
+        setter: SetterAttrs::default(),
+        validate: None,
+        name: format_ident!("{}Builder", input.ident),
+        vis: input.vis.clone(),
+        build_fn: format_ident!("build"),
+        fallible: false,
+        patch: false,
+        is_const: false,
+        derives: Vec::new(),
+    };
+    let mut pattern: Option<LitStr> = None;
+    let mut const_path: Option<Path> = None;
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("builder")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("typestate") {
+                result.typestate = true;
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                result.default = true;
+                Ok(())
+            } else if meta.path.is_ident("fallible") {
+                result.fallible = true;
+                Ok(())
+            } else if meta.path.is_ident("patch") {
+                result.patch = true;
+                Ok(())
+            } else if meta.path.is_ident("const") {
+                result.is_const = true;
+                const_path = Some(meta.path.clone());
+                Ok(())
+            } else if meta.path.is_ident("pattern") {
+                let lit: LitStr = meta.value()?.parse()?;
+                result.pattern = match lit.value().as_str() {
+                    "mutable" => Pattern::Mutable,
+                    "owned" => Pattern::Owned,
+                    "immutable" => Pattern::Immutable,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &lit,
+                            "expected `mutable`, `owned` or `immutable`",
+                        ))
+                    }
+                };
+                pattern = Some(lit);
+                Ok(())
+            } else if meta.path.is_ident("setter") {
+                parse_setter_attrs(&meta, &mut result.setter)
+            } else if meta.path.is_ident("validate") {
+                result.validate = Some(parse_validate(&meta)?);
+                Ok(())
+            } else if meta.path.is_ident("name") {
+                let name: LitStr = meta.value()?.parse()?;
+                result.name = name.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("vis") {
+                let vis: LitStr = meta.value()?.parse()?;
+                result.vis = vis.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("build_fn") {
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("name") {
+                        let name: LitStr = meta.value()?.parse()?;
+                        result.build_fn = name.parse()?;
+                        Ok(())
+                    } else {
+                        Err(meta.error("expected `name`"))
+                    }
+                })
+            } else if meta.path.is_ident("derive") {
+                meta.parse_nested_meta(|meta| {
+                    result.derives.push(meta.path);
+                    Ok(())
+                })
+            } else {
+                Err(meta.error("unknown builder option"))
+            }
+        })?;
+    }
+
+    // typestate-сеттер меняет тип билдера, поэтому всегда забирает его по значению
+    if result.typestate {
+        match pattern {
+            Some(lit) if result.pattern != Pattern::Owned => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "typestate builders always use the `owned` pattern",
+                ));
+            }
+            _ => result.pattern = Pattern::Owned,
+        }
+    }
+
+    // в const fn нельзя ни клонировать билдер, ни вызывать Default, Into и
+    // проверки, ни вернуть ошибку со списком полей
+    if let Some(path) = const_path {
+        match pattern {
+            Some(lit) if result.pattern != Pattern::Owned => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "const builders always use the `owned` pattern",
+                ));
+            }
+            _ => result.pattern = Pattern::Owned,
+        }
+        if result.typestate
+            || result.default
+            || result.fallible
+            || result.validate.is_some()
+            || result.setter.into == Some(true)
+        {
+            return Err(syn::Error::new_spanned(
+                path,
+                "`const` cannot be combined with `typestate`, `default`, `fallible`, `validate` or `setter(into)`",
+            ));
+        }
+    }
+
+    Ok(result)
+}
+
+// Опции уровня поля: #[builder(...)] над полем структуры
+#[derive(Default)]
+pub struct FieldAttrs {
+    // имя сеттера, добавляющего в коллекцию по одному элементу
+    pub each: Option<Ident>,
+    // значение незаданного поля: #[builder(default)] или #[builder(default = "expr")]
+    pub default: Option<Expr>,
+    // default без выражения, то есть Default::default()
+    pub default_trait: bool,
+    pub setter: SetterAttrs,
+    // проверка итогового значения поля: fn(&FieldType) -> Result<(), E>, E: Display
+    pub validate: Option<Path>,
+    // поле без сеттера, заполняется значением по умолчанию
+    pub skip: bool,
+    // поле без сеттера, вычисляется в build() из остальных полей
+    pub compute: Option<Expr>,
+    // тип вложенного билдера: #[builder(sub_builder)] берёт {Type}Builder рядом
+    // с типом поля, #[builder(sub_builder = "...")] задаёт его явно
+    pub sub_builder: Option<Type>,
+    // переменная окружения, из которой build() берёт незаданное поле
+    pub env: Option<LitStr>,
+    // группа взаимоисключающих полей; вид ограничения достаточно указать у одного поля
+    pub group: Option<LitStr>,
+    pub group_kind: Option<GroupKind>,
+    // поля, которые должны быть заданы вместе с этим: #[builder(requires = "password")]
+    pub requires: Vec<Ident>,
+    // опциональность поля вопреки его типу, для псевдонимов Option
+    pub optional: Option<bool>,
+    // #[builder(by_name = false)]: поле не задаётся через set_by_name
+    pub by_name: Option<bool>,
+}
+
+// {Type}Builder по тому же пути и с теми же аргументами, что и тип поля
+fn sub_builder_type(ty: &Type) -> Option<Type> {
+    let mut path = match ty {
+        Type::Path(TypePath { qself: None, path }) => path.clone(),
+        _ => return None,
+    };
+    let last = path.segments.last_mut()?;
+    last.ident = format_ident!("{}Builder", last.ident);
+    Some(Type::Path(TypePath { qself: None, path }))
+}
+
+pub fn parse_field_attrs(field: &Field) -> syn::Result<FieldAttrs> {
+    let mut result = FieldAttrs::default();
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("builder")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("each") {
+                let setter: LitStr = meta.value()?.parse()?;
+                result.each = Some(setter.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                result.default = Some(if meta.input.peek(Token![=]) {
+                    let expr: LitStr = meta.value()?.parse()?;
+                    expr.parse()?
+                } else {
+                    result.default_trait = true;
+                    syn::parse_quote! { ::core::default::Default::default() }
+                });
+                Ok(())
+            } else if meta.path.is_ident("setter") {
+                parse_setter_attrs(&meta, &mut result.setter)
+            } else if meta.path.is_ident("validate") {
+                result.validate = Some(parse_validate(&meta)?);
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                result.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("compute") {
+                let expr: LitStr = meta.value()?.parse()?;
+                result.compute = Some(expr.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("group") {
+                result.group = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("exactly_one") {
+                result.group_kind = Some(GroupKind::ExactlyOne);
+                Ok(())
+            } else if meta.path.is_ident("at_most_one") {
+                result.group_kind = Some(GroupKind::AtMostOne);
+                Ok(())
+            } else if meta.path.is_ident("requires") {
+                let field: LitStr = meta.value()?.parse()?;
+                result.requires.push(field.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("optional") {
+                result.optional = Some(parse_flag(&meta)?);
+                Ok(())
+            } else if meta.path.is_ident("by_name") {
+                result.by_name = Some(parse_flag(&meta)?);
+                Ok(())
+            } else if meta.path.is_ident("env") {
+                result.env = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("sub_builder") {
+                result.sub_builder = Some(if meta.input.peek(Token![=]) {
+                    let ty: LitStr = meta.value()?.parse()?;
+                    ty.parse()?
+                } else {
+                    sub_builder_type(&field.ty).ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            &field.ty,
+                            "`sub_builder` requires a struct type, or the builder type given as `sub_builder = \"...\"`",
+                        )
+                    })?
+                });
+                Ok(())
+            } else {
+                Err(syn::Error::new_spanned(&attr.meta, "expected `builder(each = \"...\")`"))
+            }
+        })?;
+    }
+
+    if result.group_kind.is_some() && result.group.is_none() {
+        return Err(syn::Error::new_spanned(
+            field,
+            "`exactly_one` and `at_most_one` require `group = \"...\"`",
+        ));
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,83 @@
+use crate::attrs::StructAttrs;
+use crate::utils::field_name;
+use crate::{build_order, gen_optional_value, gen_setter_value, stored_fields, FieldInfo};
+
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{Generics, Ident};
+
+// Билдер для const-контекста: builder(), сеттеры и build() - const fn.
+// Сеттеры забирают билдер по значению, build() не клонирует поля, а незаданное
+// обязательное поле - паника с его именем, в const это ошибка компиляции.
+// Типы полей и выражения default должны быть допустимы в const fn
+pub fn gen_const_builder_fn(builder_name: &Ident, generics: &Generics, fields: &[FieldInfo]) -> TokenStream2 {
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let field_names = stored_fields(fields).map(|info| &info.name);
+    quote::quote! {
+        pub const fn builder() -> #builder_name #ty_generics {
+            #builder_name {
+                #(#field_names: ::core::option::Option::None),*
+            }
+        }
+    }
+}
+
+pub fn gen_const_setters(struct_attrs: &StructAttrs, fields: &[FieldInfo]) -> TokenStream2 {
+    let vis = &struct_attrs.vis;
+    let setters = stored_fields(fields).map(|info| {
+        let name = &info.name;
+        let (param_type, value) = gen_setter_value(info);
+        quote::quote! {
+            #vis const fn #name(mut self, val: #param_type) -> Self {
+                self.#name = #value;
+                self
+            }
+        }
+    });
+    quote::quote! { #(#setters)* }
+}
+
+pub fn gen_const_build(
+    structure_name: &Ident,
+    builder_name: &Ident,
+    struct_attrs: &StructAttrs,
+    generics: &Generics,
+    fields: &[FieldInfo],
+) -> TokenStream2 {
+    let vis = &struct_attrs.vis;
+    let build_fn = &struct_attrs.build_fn;
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    let ordered = build_order(fields);
+    let field_names: Vec<_> = ordered.iter().map(|info| &info.name).collect();
+    let field_values = ordered.iter().map(|info| {
+        let name = &info.name;
+        if let Some(compute) = &info.compute {
+            return quote::quote! { #compute };
+        }
+        if !info.is_mandatory {
+            return gen_optional_value(info, quote::quote! { self.#name });
+        }
+        let message = format!(
+            "`{}::{}()`: missing required field `{}`",
+            builder_name,
+            build_fn,
+            field_name(name)
+        );
+        quote::quote! {
+            match self.#name {
+                ::core::option::Option::Some(__value) => __value,
+                ::core::option::Option::None => ::core::panic!(#message),
+            }
+        }
+    });
+
+    quote::quote! {
+        #vis const fn #build_fn(self) -> #structure_name #ty_generics {
+            #(let #field_names = #field_values;)*
+
+            #structure_name {
+                #(#field_names),*
+            }
+        }
+    }
+}
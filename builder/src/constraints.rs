@@ -0,0 +1,177 @@
+use crate::attrs::GroupKind;
+use crate::utils::field_name;
+use crate::FieldInfo;
+
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{Ident, LitStr};
+
+// Группа, в которую входит поле, с видом ограничения, общим для всей группы
+pub struct FieldGroup {
+    pub name: String,
+    pub kind: GroupKind,
+}
+
+// Группа со всеми участниками в порядке объявления
+pub struct Group<'a> {
+    pub name: &'a str,
+    pub kind: GroupKind,
+    pub members: Vec<&'a FieldInfo>,
+}
+
+// Объявление группы у одного поля, как оно записано в атрибуте
+pub struct GroupDecl {
+    pub field: usize,
+    pub name: LitStr,
+    pub kind: Option<GroupKind>,
+}
+
+// Вид ограничения берётся у любого поля группы и должен совпадать у всех,
+// кто его указал
+pub fn resolve_groups(fields: &mut [FieldInfo], decls: Vec<GroupDecl>) -> syn::Result<()> {
+    for decl in &decls {
+        let name = decl.name.value();
+        let mut kinds = decls
+            .iter()
+            .filter(|other| other.name.value() == name)
+            .filter_map(|other| other.kind);
+        let kind = kinds.next().ok_or_else(|| {
+            syn::Error::new_spanned(
+                &decl.name,
+                format!("group `{}` needs `exactly_one` or `at_most_one`", name),
+            )
+        })?;
+        if kinds.any(|other| other != kind) {
+            return Err(syn::Error::new_spanned(
+                &decl.name,
+                format!("conflicting constraints for group `{}`", name),
+            ));
+        }
+        fields[decl.field].group = Some(FieldGroup { name, kind });
+    }
+    Ok(())
+}
+
+// requires может ссылаться только на другое поле с сеттером
+pub fn check_requires(fields: &[FieldInfo]) -> syn::Result<()> {
+    for info in fields {
+        for required in &info.requires {
+            if *required == info.name {
+                return Err(syn::Error::new_spanned(required, "a field cannot require itself"));
+            }
+            let target = fields.iter().find(|other| other.name == *required);
+            match target {
+                Some(target) if target.compute.is_none() && target.sub_builder.is_none() => {}
+                Some(_) => {
+                    return Err(syn::Error::new_spanned(
+                        required,
+                        "`requires` must name a field with a setter",
+                    ))
+                }
+                None => {
+                    return Err(syn::Error::new_spanned(
+                        required,
+                        format!("unknown field `{}`", required),
+                    ))
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// Группы в порядке первого упоминания
+pub fn groups(fields: &[FieldInfo]) -> Vec<Group<'_>> {
+    let mut groups: Vec<Group> = Vec::new();
+    for info in fields {
+        let Some(group) = &info.group else {
+            continue;
+        };
+        match groups.iter_mut().find(|known| known.name == group.name) {
+            Some(known) => known.members.push(info),
+            None => groups.push(Group {
+                name: &group.name,
+                kind: group.kind,
+                members: vec![info],
+            }),
+        }
+    }
+    groups
+}
+
+// Поля, участвующие в ограничениях: члены групп и обе стороны requires
+pub fn is_constrained(info: &FieldInfo, fields: &[FieldInfo]) -> bool {
+    info.group.is_some()
+        || !info.requires.is_empty()
+        || fields.iter().any(|other| other.requires.contains(&info.name))
+}
+
+// Проверки групп и requires в build(). is_set даёт выражение "сеттер поля вызывался"
+pub fn gen_constraint_checks(
+    error_name: &Ident,
+    fields: &[FieldInfo],
+    is_set: impl Fn(&FieldInfo) -> TokenStream2,
+) -> TokenStream2 {
+    let group_checks = groups(fields).into_iter().map(|group| {
+        let name = group.name;
+        let member_names: Vec<_> = group.members.iter().map(|info| field_name(&info.name)).collect();
+        let set_checks = group.members.iter().zip(&member_names).map(|(info, member)| {
+            let is_set = is_set(info);
+            quote::quote! {
+                if #is_set {
+                    set.push(#member);
+                }
+            }
+        });
+        let unset_check = match group.kind {
+            GroupKind::ExactlyOne => quote::quote! {
+                if set.is_empty() {
+                    return ::core::result::Result::Err(#error_name::GroupUnset {
+                        group: #name,
+                        fields: ::std::vec![#(#member_names),*],
+                    });
+                }
+            },
+            GroupKind::AtMostOne => TokenStream2::new(),
+        };
+        quote::quote! {
+            {
+                let mut set = ::std::vec::Vec::new();
+                #(#set_checks)*
+                if set.len() > 1 {
+                    return ::core::result::Result::Err(#error_name::GroupConflict {
+                        group: #name,
+                        fields: set,
+                    });
+                }
+                #unset_check
+            }
+        }
+    });
+
+    let mut requires_checks = Vec::new();
+    for info in fields {
+        let field = field_name(&info.name);
+        for required in &info.requires {
+            let target = fields
+                .iter()
+                .find(|other| other.name == *required)
+                .expect("checked by check_requires");
+            let is_field_set = is_set(info);
+            let is_target_set = is_set(target);
+            let required = field_name(required);
+            requires_checks.push(quote::quote! {
+                if #is_field_set && !#is_target_set {
+                    return ::core::result::Result::Err(#error_name::MissingRequired {
+                        field: #field,
+                        required: #required,
+                    });
+                }
+            });
+        }
+    }
+
+    quote::quote! {
+        #(#group_checks)*
+        #(#requires_checks)*
+    }
+}
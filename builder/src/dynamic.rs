@@ -0,0 +1,145 @@
+use crate::utils::{field_name, CollectionItem};
+use crate::{stored_fields, FieldInfo};
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use syn::visit::Visit;
+use syn::{Ident, Lifetime, Type, Visibility};
+
+// Описание поля для загрузчиков конфигурации: {Builder}FieldMeta
+pub fn gen_field_meta_code(builder_name: &Ident, vis: &Visibility) -> TokenStream2 {
+    let meta_name = format_ident!("{}FieldMeta", builder_name);
+    quote::quote! {
+        #[derive(
+            ::core::fmt::Debug,
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+        )]
+        #vis struct #meta_name {
+            #vis name: &'static str,
+            // тип, как он записан в структуре
+            #vis ty: &'static str,
+            #vis required: bool,
+            // doc-комментарий поля, строки через \n
+            #vis doc: &'static str,
+        }
+    }
+}
+
+// FIELDS: поля с сеттером в порядке объявления
+pub fn gen_fields_table(builder_name: &Ident, vis: &Visibility, fields: &[FieldInfo]) -> TokenStream2 {
+    let meta_name = format_ident!("{}FieldMeta", builder_name);
+    let entries = stored_fields(fields).map(|info| {
+        let name = field_name(&info.name);
+        let ty = &info.type_name;
+        let required = info.is_mandatory;
+        let doc = &info.doc;
+        quote::quote! {
+            #meta_name { name: #name, ty: #ty, required: #required, doc: #doc }
+        }
+    });
+    quote::quote! {
+        #vis const FIELDS: &'static [#meta_name] = &[#(#entries),*];
+    }
+}
+
+// set_by_name("timeout", "30"): значение разбирается через FromStr. Поле с each
+// получает ещё один элемент; словари, вложенные билдеры, поля с by_name = false
+// и типы с временами жизни (&str) так не задаются: границы, отличающиеся только
+// временем жизни, компилятор не различает. Метод доступен, только если все
+// задаваемые так типы реализуют FromStr
+pub fn gen_set_by_name(error_name: &Ident, vis: &Visibility, fields: &[FieldInfo]) -> TokenStream2 {
+    let mut bounds = Vec::new();
+    let arms = stored_fields(fields).map(|info| {
+        let name = &info.name;
+        let field = field_name(name);
+        let parse = |ty: TokenStream2| {
+            quote::quote! {
+                match <#ty as ::core::str::FromStr>::from_str(value) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(err) => {
+                        return ::core::result::Result::Err(#error_name::InvalidField {
+                            field: #field,
+                            message: ::std::string::ToString::to_string(&err),
+                        });
+                    }
+                }
+            }
+        };
+        let unsupported = quote::quote! {
+            #field => ::core::result::Result::Err(#error_name::InvalidField {
+                field: #field,
+                message: ::std::string::ToString::to_string("cannot be set by name"),
+            }),
+        };
+        if !info.by_name {
+            return unsupported;
+        }
+        let field_type = &info.type_;
+        let (parsed_type, body): (&Type, _) = match (&info.sub_builder, info.each.as_ref().map(|each| &each.item)) {
+            (None, Some(CollectionItem::Single(item))) => {
+                let value = parse(quote::quote! { #item });
+                (
+                    item,
+                    quote::quote! {
+                        ::core::iter::Extend::extend(
+                            self.#name.get_or_insert_with(::core::default::Default::default),
+                            ::core::iter::once(#value),
+                        );
+                    },
+                )
+            }
+            (None, None) => {
+                let value = parse(quote::quote! { #field_type });
+                (
+                    field_type,
+                    quote::quote! { self.#name = ::core::option::Option::Some(#value); },
+                )
+            }
+            _ => return unsupported,
+        };
+        let mut lifetimes = HasLifetime(false);
+        lifetimes.visit_type(parsed_type);
+        if lifetimes.0 {
+            return unsupported;
+        }
+        bounds.push(quote::quote! {
+            for<'__a> #parsed_type: ::core::str::FromStr,
+            for<'__a> <#parsed_type as ::core::str::FromStr>::Err: ::core::fmt::Display
+        });
+        quote::quote! {
+            #field => {
+                #body
+                ::core::result::Result::Ok(())
+            }
+        }
+    });
+    let arms: Vec<_> = arms.collect();
+
+    quote::quote! {
+        #vis fn set_by_name(&mut self, name: &str, value: &str) -> ::core::result::Result<(), #error_name>
+        where
+            #(#bounds),*
+        {
+            match name {
+                #(#arms)*
+                _ => ::core::result::Result::Err(#error_name::UnknownField(::std::string::ToString::to_string(name))),
+            }
+        }
+    }
+}
+
+// Есть ли в типе время жизни: &T, &'a T, Cow<'a, str>, dyn Trait + 'a
+struct HasLifetime(bool);
+
+impl Visit<'_> for HasLifetime {
+    fn visit_lifetime(&mut self, _: &Lifetime) {
+        self.0 = true;
+    }
+
+    fn visit_type_reference(&mut self, _: &syn::TypeReference) {
+        self.0 = true;
+    }
+}
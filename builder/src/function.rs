@@ -0,0 +1,345 @@
+use crate::attrs::parse_struct_attrs;
+use crate::dynamic::{gen_field_meta_code, gen_fields_table, gen_set_by_name};
+use crate::utils::{field_name, to_upper_camel_case};
+use crate::{
+    gen_accessors, gen_build_receiver, gen_builder_struct_code, gen_error_code, gen_field_setters, error_variants,
+    gen_resolve_fields, get_fields_info, stored_fields,
+};
+
+use std::collections::HashSet;
+
+use crate::attrs::Pattern;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, ToTokens};
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    DeriveInput, FnArg, FieldsNamed, GenericParam, Generics, ItemFn, Lifetime, ParenthesizedGenericArguments, Pat,
+    PatIdent, ReturnType, Type, TypeBareFn, TypeImplTrait, TypeReference, WherePredicate,
+};
+
+// Билдер для свободной функции: #[builder] fn spawn(executable: String, ...)
+// даёт spawn_builder().executable(..).call(). Параметры разбираются как поля
+// структуры SpawnBuilder, поэтому each, default, setter и остальные опции поля
+// работают так же, как в derive. Опции в #[builder(...)] - опции уровня структуры.
+pub fn expand_fn(args: TokenStream2, mut item: ItemFn) -> syn::Result<TokenStream2> {
+    if let Some(receiver) = item.sig.receiver() {
+        return Err(syn::Error::new_spanned(
+            receiver,
+            "#[builder] supports only free functions",
+        ));
+    }
+    if let Some(variadic) = &item.sig.variadic {
+        return Err(syn::Error::new_spanned(
+            variadic,
+            "#[builder] does not support variadic functions",
+        ));
+    }
+
+    // параметры как именованные поля; атрибуты builder с самой функции убираются.
+    // пропущенные времена жизни в типах получают имена и становятся параметрами билдера
+    let mut params = Vec::new();
+    let mut param_types = Vec::new();
+    let mut elided = NameElided::default();
+    for input in item.sig.inputs.iter_mut() {
+        let FnArg::Typed(param) = input else {
+            unreachable!("receiver is rejected above");
+        };
+        let ident = match &*param.pat {
+            Pat::Ident(PatIdent {
+                ident,
+                by_ref: None,
+                subpat: None,
+                ..
+            }) => ident.clone(),
+            pat => {
+                return Err(syn::Error::new_spanned(
+                    pat,
+                    "#[builder] expects plain parameter names",
+                ))
+            }
+        };
+        let (builder_attrs, attrs) = param
+            .attrs
+            .drain(..)
+            .partition::<Vec<_>, _>(|attr| attr.path().is_ident("builder"));
+        param.attrs = attrs;
+        let mut ty = (*param.ty).clone();
+        elided.visit_type_mut(&mut ty);
+        if let Some(error) = elided.error.take() {
+            return Err(error);
+        }
+        params.push(quote::quote! { #(#builder_attrs)* #ident: #ty });
+        param_types.push(ty);
+    }
+    let fields: FieldsNamed = syn::parse_quote! { { #(#params),* } };
+    let (generics, call_generics) = split_generics(&item.sig.generics, elided.lifetimes, &param_types);
+
+    // опции функции разбираются как опции структуры с именем SpawnBuilder;
+    // метод сборки по умолчанию называется call
+    let fn_name = &item.sig.ident;
+    let vis = &item.vis;
+    let generics = &generics;
+    let where_clause = &generics.where_clause;
+    let struct_name = format_ident!("{}", to_upper_camel_case(&field_name(fn_name)));
+    let input: DeriveInput = syn::parse_quote! {
+        #[builder(build_fn(name = "call"))]
+        #[builder(#args)]
+        #vis struct #struct_name #generics #where_clause #fields
+    };
+    let struct_attrs = parse_struct_attrs(&input)?;
+    if struct_attrs.typestate
+        || struct_attrs.default
+        || struct_attrs.validate.is_some()
+        || struct_attrs.patch
+        || struct_attrs.is_const
+    {
+        return Err(syn::Error::new_spanned(
+            args,
+            "`typestate`, `default`, `validate`, `patch` and `const` are not supported for functions",
+        ));
+    }
+    // билдер с другими шаблонами клонирует аргументы
+    if struct_attrs.pattern != Pattern::Owned {
+        if let Some(reference) = elided.mutable.first() {
+            return Err(syn::Error::new_spanned(
+                reference,
+                "`&mut` parameters require `pattern = \"owned\"`",
+            ));
+        }
+    }
+    let fields = get_fields_info(&fields, &struct_attrs)?;
+
+    let builder_name = &struct_attrs.name;
+    let error_name = format_ident!("{}Error", builder_name);
+    let builder_fn = format_ident!("{}_builder", fn_name);
+    let call_fn = &struct_attrs.build_fn;
+    let builder_vis = &struct_attrs.vis;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let builder_struct_code = gen_builder_struct_code(builder_name, &struct_attrs, generics, &fields);
+    let setters = stored_fields(&fields)
+        .map(|info| gen_field_setters(info, struct_attrs.pattern, builder_vis));
+    let accessors = gen_accessors(&fields, struct_attrs.pattern, builder_vis);
+    let receiver = gen_build_receiver(struct_attrs.pattern);
+    let resolve_fields = gen_resolve_fields(&error_name, &struct_attrs, &fields, TokenStream2::new());
+    let error_code = gen_error_code(&error_name, builder_vis, &error_variants(&struct_attrs, &fields));
+    let field_meta_code = gen_field_meta_code(builder_name, builder_vis);
+    let fields_table = gen_fields_table(builder_name, builder_vis, &fields);
+    let set_by_name = gen_set_by_name(&error_name, builder_vis, &fields);
+
+    // аргументы в порядке объявления параметров
+    let arg_names = fields.iter().map(|info| &info.name);
+    // пропущенные времена жизни результата - единственное время жизни параметров
+    let output = match &item.sig.output {
+        ReturnType::Default => quote::quote! { () },
+        ReturnType::Type(_, ty) => {
+            let mut ty = (**ty).clone();
+            if let [lifetime] = &input_lifetimes(&param_types)[..] {
+                FillElided(lifetime.clone()).visit_type_mut(&mut ty);
+            }
+            quote::quote! { #ty }
+        }
+    };
+    let (call_impl_generics, _, call_where_clause) = call_generics.split_for_impl();
+    let asyncness = &item.sig.asyncness;
+    let unsafety = &item.sig.unsafety;
+    let call = match asyncness {
+        Some(_) => quote::quote! { #fn_name(#(#arg_names),*).await },
+        None => quote::quote! { #fn_name(#(#arg_names),*) },
+    };
+
+    Ok(quote::quote! {
+        #item
+
+        #vis fn #builder_fn #impl_generics () -> #builder_name #ty_generics #where_clause {
+            <#builder_name #ty_generics as ::core::default::Default>::default()
+        }
+
+        #builder_struct_code
+
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            #(#setters)*
+
+            #accessors
+
+            #fields_table
+
+            #set_by_name
+
+            #builder_vis #asyncness #unsafety fn #call_fn #call_impl_generics (#receiver) -> ::core::result::Result<#output, #error_name> #call_where_clause {
+                #resolve_fields
+
+                ::core::result::Result::Ok(#call)
+            }
+        }
+
+        #error_code
+
+        #field_meta_code
+    })
+}
+
+// Даёт пропущенным временам жизни (&T, '_) имена '__a0, '__a1, ...
+// impl Trait в параметре билдер хранить не может
+#[derive(Default)]
+struct NameElided {
+    lifetimes: Vec<Lifetime>,
+    mutable: Vec<TypeReference>,
+    error: Option<syn::Error>,
+}
+
+impl NameElided {
+    fn fresh(&mut self, span: Span) -> Lifetime {
+        let lifetime = Lifetime::new(&format!("'__a{}", self.lifetimes.len()), span);
+        self.lifetimes.push(lifetime.clone());
+        lifetime
+    }
+}
+
+impl VisitMut for NameElided {
+    fn visit_type_reference_mut(&mut self, ty: &mut TypeReference) {
+        if ty.lifetime.is_none() {
+            ty.lifetime = Some(self.fresh(ty.and_token.span));
+        }
+        if ty.mutability.is_some() {
+            self.mutable.push(ty.clone());
+        }
+        visit_mut::visit_type_reference_mut(self, ty);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident == "_" {
+            *lifetime = self.fresh(lifetime.apostrophe);
+        }
+    }
+
+    fn visit_type_impl_trait_mut(&mut self, ty: &mut TypeImplTrait) {
+        self.error.get_or_insert_with(|| {
+            syn::Error::new_spanned(&*ty, "#[builder] does not support `impl Trait` parameters, use a named generic")
+        });
+    }
+
+    // у fn(&T) и Fn(&T) своя область элизии
+    fn visit_type_bare_fn_mut(&mut self, _: &mut TypeBareFn) {}
+
+    fn visit_parenthesized_generic_arguments_mut(&mut self, _: &mut ParenthesizedGenericArguments) {}
+}
+
+// Подставляет заданное время жизни вместо пропущенных
+struct FillElided(Lifetime);
+
+impl VisitMut for FillElided {
+    fn visit_type_reference_mut(&mut self, ty: &mut TypeReference) {
+        if ty.lifetime.is_none() {
+            ty.lifetime = Some(self.0.clone());
+        }
+        visit_mut::visit_type_reference_mut(self, ty);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident == "_" {
+            *lifetime = self.0.clone();
+        }
+    }
+
+    fn visit_type_bare_fn_mut(&mut self, _: &mut TypeBareFn) {}
+
+    fn visit_parenthesized_generic_arguments_mut(&mut self, _: &mut ParenthesizedGenericArguments) {}
+}
+
+// Имена типов, констант и времён жизни ('a), встречающиеся в токенах
+fn mentioned_names(tokens: TokenStream2, names: &mut HashSet<String>) {
+    let mut apostrophe = false;
+    for token in tokens {
+        match &token {
+            TokenTree::Ident(ident) if apostrophe => {
+                names.insert(format!("'{}", ident));
+            }
+            TokenTree::Ident(ident) => {
+                names.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => mentioned_names(group.stream(), names),
+            _ => {}
+        }
+        apostrophe = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '\'');
+    }
+}
+
+fn mentions(tokens: TokenStream2, names: &HashSet<String>) -> bool {
+    let mut mentioned = HashSet::new();
+    mentioned_names(tokens, &mut mentioned);
+    !mentioned.is_disjoint(names)
+}
+
+// Различные времена жизни в типах параметров
+fn input_lifetimes(param_types: &[Type]) -> Vec<Lifetime> {
+    let mut names = HashSet::new();
+    for ty in param_types {
+        mentioned_names(ty.to_token_stream(), &mut names);
+    }
+    let mut lifetimes: Vec<_> = names
+        .iter()
+        .filter_map(|name| name.strip_prefix('\''))
+        .map(|name| Lifetime::new(&format!("'{}", name), Span::call_site()))
+        .collect();
+    lifetimes.sort_by_key(|lifetime| lifetime.ident.to_string());
+    lifetimes
+}
+
+// Параметры билдера - те, что встречаются в типах параметров функции.
+// Остальные (например, T только в результате) переходят на call() вместе
+// с ограничениями, которые их упоминают
+fn split_generics(fn_generics: &Generics, elided: Vec<Lifetime>, param_types: &[Type]) -> (Generics, Generics) {
+    let mut used = HashSet::new();
+    for ty in param_types {
+        mentioned_names(ty.to_token_stream(), &mut used);
+    }
+    let name = |param: &GenericParam| match param {
+        GenericParam::Lifetime(param) => format!("'{}", param.lifetime.ident),
+        GenericParam::Type(param) => param.ident.to_string(),
+        GenericParam::Const(param) => param.ident.to_string(),
+    };
+
+    let mut params: Vec<GenericParam> = elided.into_iter().map(|lifetime| syn::parse_quote! { #lifetime }).collect();
+    params.extend(fn_generics.params.iter().cloned());
+    let (mut kept, moved): (Vec<_>, Vec<_>) = params.into_iter().partition(|param| used.contains(&name(param)));
+    let moved_names: HashSet<_> = moved.iter().map(name).collect();
+
+    let mut call_predicates = Vec::new();
+    for param in &mut kept {
+        match param {
+            GenericParam::Type(param) if mentions(param.bounds.to_token_stream(), &moved_names) => {
+                let (ident, bounds) = (&param.ident, &param.bounds);
+                call_predicates.push(syn::parse_quote! { #ident: #bounds });
+                param.colon_token = None;
+                param.bounds.clear();
+            }
+            GenericParam::Lifetime(param) if mentions(param.bounds.to_token_stream(), &moved_names) => {
+                let (lifetime, bounds) = (&param.lifetime, &param.bounds);
+                call_predicates.push(syn::parse_quote! { #lifetime: #bounds });
+                param.colon_token = None;
+                param.bounds.clear();
+            }
+            _ => {}
+        }
+    }
+    let mut predicates = Vec::new();
+    for predicate in fn_generics.where_clause.iter().flat_map(|clause| &clause.predicates) {
+        match mentions(predicate.to_token_stream(), &moved_names) {
+            true => call_predicates.push(predicate.clone()),
+            false => predicates.push(predicate.clone()),
+        }
+    }
+
+    let generics = |params: Vec<GenericParam>, predicates: Vec<WherePredicate>| {
+        let mut generics = Generics {
+            params: params.into_iter().collect(),
+            ..Generics::default()
+        };
+        if !predicates.is_empty() {
+            generics.make_where_clause().predicates.extend(predicates);
+        }
+        generics
+    };
+    (generics(kept, predicates), generics(moved, call_predicates))
+}
@@ -1,180 +1,1556 @@
+mod attrs;
+mod const_fn;
+mod constraints;
+mod dynamic;
+mod function;
+mod patch;
+mod typestate;
 mod utils;
 
-use utils::extract_type_from_option;
+#[cfg(test)]
+mod tests;
 
-use syn::{Fields, Path, Type, TypePath};
+use attrs::{parse_field_attrs, parse_struct_attrs, Pattern, StructAttrs};
+use const_fn::{gen_const_build, gen_const_builder_fn, gen_const_setters};
+use constraints::{
+    check_requires, gen_constraint_checks, is_constrained, resolve_groups, FieldGroup, GroupDecl,
+};
+use dynamic::{gen_field_meta_code, gen_fields_table, gen_set_by_name};
+use patch::gen_patch_code;
+use typestate::gen_typestate_code;
+use utils::{doc_comment, extract_collection_item, field_name, type_string, CollectionItem};
+
+use syn::{Expr, Fields, Generics, LitStr, Path, Type, Visibility};
 
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::format_ident;
-use std::collections::HashMap;
 use syn::Ident;
-use syn::{parse_macro_input, Data, DeriveInput};
+use syn::{parse_macro_input, Data, DeriveInput, ItemFn};
 use syn::FieldsNamed;
 
 struct FieldInfo {
+    name: Ident,
     is_mandatory: bool,
+    is_option: bool,
     type_: Type,
+    each: Option<EachSetter>,
+    // значение, если сеттер не вызывался; вычисляется в build()
+    default: Option<Expr>,
+    // сеттер принимает impl Into<T>
+    setter_into: bool,
+    // сеттер Option-поля принимает T; иначе Option<T>, и можно передать None
+    strip_option: bool,
+    // проверка итогового значения в build()
+    validate: Option<Path>,
+    // поле без сеттера и без места в билдере: skip или compute.
+    // Вычисляется в build() после всех остальных полей
+    compute: Option<Expr>,
+    // тип вложенного билдера: поле хранится в билдере как он сам и собирается
+    // его build(), ошибки которого попадают в ошибку родителя с путём поля
+    sub_builder: Option<Type>,
+    // переменная окружения для незаданного поля, значение разбирается через FromStr
+    env: Option<LitStr>,
+    // группа взаимоисключающих полей
+    group: Option<FieldGroup>,
+    // поля, которые должны быть заданы, если задано это
+    requires: Vec<Ident>,
+    // задаётся ли поле через set_by_name; false убирает и границу FromStr
+    by_name: bool,
+    // тип поля, как он записан в структуре, и его doc-комментарий - для FIELDS
+    type_name: String,
+    doc: String,
+}
+
+// Поля, которые хранятся в билдере и задаются сеттерами
+fn stored_fields(fields: &[FieldInfo]) -> impl Iterator<Item = &FieldInfo> {
+    fields.iter().filter(|info| info.compute.is_none())
 }
 
-fn extract_fields(input: &DeriveInput) -> &FieldsNamed {
+// Порядок вычисления полей в build(): сначала заданные через билдер,
+// затем вычисляемые - им доступны итоговые значения остальных полей
+fn build_order(fields: &[FieldInfo]) -> Vec<&FieldInfo> {
+    stored_fields(fields)
+        .chain(fields.iter().filter(|info| info.compute.is_some()))
+        .collect()
+}
+
+// Сеттер, пополняющий коллекцию по одному элементу: #[builder(each = "...")]
+struct EachSetter {
+    name: Ident,
+    item: CollectionItem,
+}
+
+fn extract_fields(input: &DeriveInput) -> syn::Result<&FieldsNamed> {
     match &input.data {
         Data::Struct(ref s) => match &s.fields {
-            Fields::Named(ref fields) => fields,
-            _ => panic!("can be derived only for structs with named fields"),
+            Fields::Named(ref fields) => Ok(fields),
+            Fields::Unnamed(ref fields) => Err(syn::Error::new_spanned(
+                fields,
+                "can be derived only for structs with named fields",
+            )),
+            Fields::Unit => Err(syn::Error::new_spanned(
+                &input.ident,
+                "can be derived only for structs with named fields",
+            )),
         },
-        _ => panic!("can be derived only for structs"),
+        Data::Enum(ref e) => Err(syn::Error::new_spanned(
+            e.enum_token,
+            "can be derived only for structs",
+        )),
+        Data::Union(ref u) => Err(syn::Error::new_spanned(
+            u.union_token,
+            "can be derived only for structs",
+        )),
     }
 }
 
-fn get_fields_info(fields: &FieldsNamed) -> HashMap<Ident, FieldInfo> {
-    let mut infos = HashMap::new();
+// Данные полей в порядке объявления: от него зависят порядок сеттеров в
+// раскрытии и то, на какие поля могут ссылаться выражения default
+fn get_fields_info(fields: &FieldsNamed, struct_attrs: &StructAttrs) -> syn::Result<Vec<FieldInfo>> {
+    let mut infos = Vec::new();
+    let mut group_decls = Vec::new();
 
     for f in fields.named.iter() {
-        match f.ty {
-            Type::Path(
-                TypePath {
-                    qself: None,
-                    path: Path {
-                        leading_colon: None,
-                        // Punctuated<PathSegment, Colon2>
-                        ref segments,
-                    },
-                }
-            ) => {
-                // Обязательность поля
-                let is_mandatory = segments[0].ident != "Option";
-
-                // имя поля
-                let f_name = f.ident.clone().expect("Named field expected");
-
-                //тип поля
-                let f_type = if is_mandatory {
-                    &f.ty
-                } else {
-                    extract_type_from_option(&f.ty).unwrap_or(&f.ty)
-                };
-                infos.insert(f_name.clone(), FieldInfo { is_mandatory, type_: f_type.clone() });
-            }
-            _ => unreachable!(),
+        let attrs = parse_field_attrs(f)?;
+
+        // Обязательность поля по его типу: Option<T> под любым из путей std/core.
+        // #[builder(optional)] объявляет опциональным псевдоним Option,
+        // #[builder(optional = false)] - наоборот, свой тип с именем Option
+        let option_inner = field_type::option_inner(&f.ty);
+        let is_option = attrs.optional.unwrap_or(option_inner.is_some());
+
+        // имя поля
+        let f_name = f
+            .ident
+            .clone()
+            .ok_or_else(|| syn::Error::new_spanned(f, "named field expected"))?;
+
+        // поштучный сеттер коллекции
+        let each = match attrs.each {
+            Some(_) if is_option => {
+                return Err(syn::Error::new_spanned(
+                    &f.ty,
+                    "`each` is not supported for Option fields",
+                ));
+            }
+            Some(name) => Some(EachSetter {
+                name,
+                item: extract_collection_item(&f.ty),
+            }),
+            None => None,
+        };
+
+        // Default::default() не const fn: const-билдеру нужно явное выражение
+        if struct_attrs.is_const && (attrs.default_trait || (attrs.skip && attrs.default.is_none())) {
+            return Err(syn::Error::new_spanned(
+                &f_name,
+                "const builders need an explicit `default = \"...\"`: `Default::default()` is not const",
+            ));
+        }
+
+        // явное значение по умолчанию важнее Default всей структуры,
+        // коллекция с each изначально пустая
+        let has_default = attrs.default.is_some();
+        let mut default = match attrs.default {
+            Some(expr) => Some(expr),
+            None if struct_attrs.default => Some(syn::parse_quote! { __default.#f_name }),
+            None if each.is_some() => {
+                Some(syn::parse_quote! { ::core::default::Default::default() })
+            }
+            None => None,
+        };
+
+        // skip берёт значение по умолчанию как обычное поле, но без сеттера;
+        // настройки сеттера к таким полям неприменимы
+        let compute = match (attrs.skip, attrs.compute) {
+            (true, Some(expr)) => {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "`skip` and `compute` cannot be used together",
+                ));
+            }
+            (false, Some(expr)) if has_default => {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "`compute` cannot be used together with `default`",
+                ));
+            }
+            (true, None) => Some(
+                default
+                    .take()
+                    .unwrap_or_else(|| syn::parse_quote! { ::core::default::Default::default() }),
+            ),
+            (false, compute) => compute,
         };
+        if compute.is_some() {
+            if each.is_some() || attrs.setter.into.is_some() || attrs.setter.strip_option.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &f_name,
+                    "`each` and `setter` have no effect on a field without a setter",
+                ));
+            }
+            default = None;
+        }
+
+        // вложенный билдер сам знает свои обязательные поля и значения по умолчанию
+        if let Some(sub_builder) = &attrs.sub_builder {
+            if struct_attrs.typestate {
+                return Err(syn::Error::new_spanned(
+                    sub_builder,
+                    "`sub_builder` is not supported by typestate builders",
+                ));
+            }
+            if is_option || each.is_some() || has_default || compute.is_some() {
+                return Err(syn::Error::new_spanned(
+                    sub_builder,
+                    "`sub_builder` cannot be combined with Option fields, `each`, `default`, `skip` or `compute`",
+                ));
+            }
+            default = None;
+        }
+
+        // значение из окружения заменяет незаданный сеттер, поэтому нужно самому полю
+        if let Some(env) = &attrs.env {
+            if struct_attrs.typestate {
+                return Err(syn::Error::new_spanned(
+                    env,
+                    "`env` is not supported by typestate builders",
+                ));
+            }
+            if compute.is_some() || attrs.sub_builder.is_some() {
+                return Err(syn::Error::new_spanned(
+                    env,
+                    "`env` cannot be combined with `skip`, `compute` or `sub_builder`",
+                ));
+            }
+        }
+
+        let is_mandatory =
+            !is_option && default.is_none() && compute.is_none() && attrs.sub_builder.is_none();
+
+        // в группе имеет смысл только поле, которое можно не задавать
+        if let Some(group) = attrs.group {
+            if is_mandatory || compute.is_some() || attrs.sub_builder.is_some() {
+                return Err(syn::Error::new_spanned(
+                    group,
+                    "group members must be optional fields with a setter",
+                ));
+            }
+            group_decls.push(GroupDecl {
+                field: infos.len(),
+                name: group,
+                kind: attrs.group_kind,
+            });
+        }
+
+        // форма сеттера: опция поля важнее опции всей структуры
+        if attrs.setter.strip_option.is_some() && !is_option {
+            return Err(syn::Error::new_spanned(
+                &f.ty,
+                "`strip_option` applies only to Option fields",
+            ));
+        }
+        let setter_into = attrs.setter.into.or(struct_attrs.setter.into).unwrap_or(false);
+        let strip_option = attrs
+            .setter
+            .strip_option
+            .or(struct_attrs.setter.strip_option)
+            .unwrap_or(true);
+
+        //тип поля; T псевдонима Option<T> достаётся через IntoIterator
+        let f_type: Type = match (is_option, option_inner) {
+            (true, Some(inner)) => inner.clone(),
+            (true, None) => {
+                let ty = &f.ty;
+                syn::parse_quote! { <#ty as ::core::iter::IntoIterator>::Item }
+            }
+            (false, _) => f.ty.clone(),
+        };
+        infos.push(FieldInfo {
+            name: f_name,
+            is_mandatory,
+            is_option,
+            type_: f_type,
+            each,
+            default,
+            setter_into,
+            strip_option,
+            validate: attrs.validate,
+            compute,
+            sub_builder: attrs.sub_builder,
+            env: attrs.env,
+            group: None,
+            requires: attrs.requires,
+            by_name: attrs.by_name.unwrap_or(true),
+            type_name: type_string(&f.ty),
+            doc: doc_comment(&f.attrs),
+        });
+    };
+
+    resolve_groups(&mut infos, group_decls)?;
+    check_requires(&infos)?;
+
+    // в typestate сеттер ограниченного поля меняет тип билдера и принимает значение целиком
+    if struct_attrs.typestate {
+        for info in &infos {
+            if is_constrained(info, &infos) && (info.each.is_some() || !info.strip_option) {
+                return Err(syn::Error::new_spanned(
+                    &info.name,
+                    "`each` and `setter(strip_option = false)` are not supported for constrained fields of typestate builders",
+                ));
+            }
+        }
+    }
+
+    // const fn может только перекладывать значения: без Extend, Into,
+    // проверок, окружения и вложенных билдеров
+    if struct_attrs.is_const {
+        for info in &infos {
+            let unsupported = if info.each.is_some() {
+                Some("each")
+            } else if info.setter_into {
+                Some("setter(into)")
+            } else if info.validate.is_some() {
+                Some("validate")
+            } else if info.env.is_some() {
+                Some("env")
+            } else if info.sub_builder.is_some() {
+                Some("sub_builder")
+            } else if info.group.is_some() {
+                Some("group")
+            } else if !info.requires.is_empty() {
+                Some("requires")
+            } else {
+                None
+            };
+            if let Some(option) = unsupported {
+                return Err(syn::Error::new_spanned(
+                    &info.name,
+                    format!("`{}` is not supported by const builders", option),
+                ));
+            }
+        }
+    }
+
+    Ok(infos)
+}
+
+// Сеттер в форме, заданной pattern. body получает выражение-билдер,
+// поля которого нужно изменить
+fn gen_setter(
+    pattern: Pattern,
+    vis: &Visibility,
+    name: &Ident,
+    params: TokenStream2,
+    bounds: TokenStream2,
+    body: impl Fn(&TokenStream2) -> TokenStream2,
+) -> TokenStream2 {
+    match pattern {
+        Pattern::Mutable => {
+            let body = body(&quote::quote! { self });
+            quote::quote! {
+                #vis fn #name #params -> &mut Self #bounds {
+                    #body
+                    self
+                }
+            }
+        }
+        Pattern::Owned => {
+            let body = body(&quote::quote! { self });
+            quote::quote! {
+                #vis fn #name #params -> Self #bounds {
+                    #body
+                    self
+                }
+            }
+        }
+        Pattern::Immutable => {
+            let body = body(&quote::quote! { __builder });
+            quote::quote! {
+                #vis fn #name #params -> Self #bounds {
+                    let mut __builder = ::core::clone::Clone::clone(self);
+                    #body
+                    __builder
+                }
+            }
+        }
+    }
+}
+
+// Тип аргумента сеттера "целиком" и значение, которое он сохраняет в билдер
+fn gen_setter_value(info: &FieldInfo) -> (TokenStream2, TokenStream2) {
+    let field_type = &info.type_;
+    match (info.is_option && !info.strip_option, info.setter_into) {
+        (false, false) => (
+            quote::quote! { #field_type },
+            quote::quote! { ::core::option::Option::Some(val) },
+        ),
+        (false, true) => (
+            quote::quote! { impl ::core::convert::Into<#field_type> },
+            quote::quote! { ::core::option::Option::Some(::core::convert::Into::into(val)) },
+        ),
+        (true, false) => (
+            quote::quote! { ::core::option::Option<#field_type> },
+            quote::quote! { val },
+        ),
+        (true, true) => (
+            quote::quote! { impl ::core::convert::Into<::core::option::Option<#field_type>> },
+            quote::quote! { ::core::convert::Into::into(val) },
+        ),
+    }
+}
+
+// Сеттеры поля: целиком и/или поштучно для коллекций
+fn gen_field_setters(info: &FieldInfo, pattern: Pattern, vis: &Visibility) -> TokenStream2 {
+    let name = &info.name;
+    let receiver = match pattern {
+        Pattern::Mutable => quote::quote! { &mut self },
+        Pattern::Owned => quote::quote! { mut self },
+        Pattern::Immutable => quote::quote! { &self },
+    };
+
+    if let Some(sub_builder) = &info.sub_builder {
+        return gen_sub_builder_setters(info, sub_builder, pattern, vis, receiver);
+    }
+
+    let (param_type, value) = gen_setter_value(info);
+    let whole_setter = gen_setter(
+        pattern,
+        vis,
+        name,
+        quote::quote! { (#receiver, val: #param_type) },
+        quote::quote! {},
+        |builder| quote::quote! { #builder.#name = #value; },
+    );
+
+    let each = match info.each {
+        Some(ref each) => each,
+        None => return whole_setter,
+    };
+
+    let each_name = &each.name;
+    let extend_name = format_ident!("extend_{}", name);
+    let (each_params, each_value, iter_item) = match (&each.item, info.setter_into) {
+        (CollectionItem::Single(item), false) => (
+            quote::quote! { val: #item },
+            quote::quote! { val },
+            quote::quote! { #item },
+        ),
+        (CollectionItem::Single(item), true) => (
+            quote::quote! { val: impl ::core::convert::Into<#item> },
+            quote::quote! { ::core::convert::Into::into(val) },
+            quote::quote! { #item },
+        ),
+        (CollectionItem::Pair(key, value), false) => (
+            quote::quote! { key: #key, value: #value },
+            quote::quote! { (key, value) },
+            quote::quote! { (#key, #value) },
+        ),
+        (CollectionItem::Pair(key, value), true) => (
+            quote::quote! {
+                key: impl ::core::convert::Into<#key>,
+                value: impl ::core::convert::Into<#value>
+            },
+            quote::quote! { (::core::convert::Into::into(key), ::core::convert::Into::into(value)) },
+            quote::quote! { (#key, #value) },
+        ),
+    };
+
+    // если поштучный сеттер назван как поле, сеттер "целиком" не генерируем
+    let whole_setter = if *each_name == *name {
+        None
+    } else {
+        Some(whole_setter)
+    };
+
+    let each_setter = gen_setter(
+        pattern,
+        vis,
+        each_name,
+        quote::quote! { (#receiver, #each_params) },
+        quote::quote! {},
+        |builder| {
+            quote::quote! {
+                ::core::iter::Extend::extend(
+                    #builder.#name.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(#each_value),
+                );
+            }
+        },
+    );
+
+    let extend_setter = gen_setter(
+        pattern,
+        vis,
+        &extend_name,
+        quote::quote! { <__I>(#receiver, iter: __I) },
+        quote::quote! { where __I: ::core::iter::IntoIterator<Item = #iter_item> },
+        |builder| {
+            quote::quote! {
+                ::core::iter::Extend::extend(
+                    #builder.#name.get_or_insert_with(::core::default::Default::default),
+                    iter,
+                );
+            }
+        },
+    );
+
+    quote::quote! {
+        #whole_setter
+        #each_setter
+        #extend_setter
+    }
+}
+
+// Сеттеры вложенного билдера: замыкание над ним, .tls(|b| b.cert(..).key(..)),
+// и доступ к нему по ссылке, .tls_mut(). Замыкание получает и возвращает &mut, поэтому
+// вложенный билдер должен использовать pattern по умолчанию
+fn gen_sub_builder_setters(
+    info: &FieldInfo,
+    sub_builder: &Type,
+    pattern: Pattern,
+    vis: &Visibility,
+    receiver: TokenStream2,
+) -> TokenStream2 {
+    let name = &info.name;
+    let mut_name = format_ident!("{}_mut", name);
+
+    let closure_setter = gen_setter(
+        pattern,
+        vis,
+        name,
+        quote::quote! { <__F>(#receiver, configure: __F) },
+        quote::quote! { where __F: ::core::ops::FnOnce(&mut #sub_builder) -> &mut #sub_builder },
+        |builder| quote::quote! { configure(&mut #builder.#name); },
+    );
+
+    quote::quote! {
+        #closure_setter
+
+        #vis fn #mut_name(&mut self) -> &mut #sub_builder {
+            &mut self.#name
+        }
+    }
+}
+
+// Доступ к полю недостроенного билдера: x_ref(), is_x_set() и clear_x().
+// clear_x() возвращает билдер так же, как сеттеры. У вложенного билдера
+// x_ref() отдаёт его самого, а clear_x() сбрасывает его в Default
+fn gen_field_accessors(info: &FieldInfo, pattern: Pattern, vis: &Visibility) -> TokenStream2 {
+    let name = &info.name;
+    let ref_name = format_ident!("{}_ref", name);
+    let is_set_name = format_ident!("is_{}_set", name);
+    let clear_name = format_ident!("clear_{}", name);
+    let receiver = match pattern {
+        Pattern::Mutable => quote::quote! { &mut self },
+        Pattern::Owned => quote::quote! { mut self },
+        Pattern::Immutable => quote::quote! { &self },
     };
 
-    infos
+    if let Some(sub_builder) = &info.sub_builder {
+        let clear = gen_setter(
+            pattern,
+            vis,
+            &clear_name,
+            quote::quote! { (#receiver) },
+            quote::quote! {},
+            |builder| quote::quote! { #builder.#name = ::core::default::Default::default(); },
+        );
+        return quote::quote! {
+            #vis fn #ref_name(&self) -> &#sub_builder {
+                &self.#name
+            }
+
+            #clear
+        };
+    }
+
+    let field_type = &info.type_;
+    let clear = gen_setter(
+        pattern,
+        vis,
+        &clear_name,
+        quote::quote! { (#receiver) },
+        quote::quote! {},
+        |builder| quote::quote! { #builder.#name = ::core::option::Option::None; },
+    );
+    quote::quote! {
+        #vis fn #ref_name(&self) -> ::core::option::Option<&#field_type> {
+            ::core::option::Option::as_ref(&self.#name)
+        }
+
+        #vis fn #is_set_name(&self) -> bool {
+            ::core::option::Option::is_some(&self.#name)
+        }
+
+        #clear
+    }
 }
 
-fn gen_builder_struct_code(structure_name: &Ident, fields: &FieldsNamed) -> TokenStream2 {
-    let struct_fields = fields.named.iter().map(|field| {
+// Обязательные поля, которые build() сейчас посчитал бы незаданными.
+// Поле с env не пропущено, если переменная окружения задана
+fn gen_missing_fields(fields: &[FieldInfo], vis: &Visibility) -> TokenStream2 {
+    let checks = fields.iter().filter(|info| info.is_mandatory).map(|info| {
+        let name = &info.name;
+        let field = field_name(name);
+        let unset = match &info.env {
+            Some(env) => quote::quote! {
+                self.#name.is_none() && ::std::env::var_os(#env).is_none()
+            },
+            None => quote::quote! { self.#name.is_none() },
+        };
         quote::quote! {
-            #field
+            if #unset {
+                missing.push(#field);
+            }
+        }
+    });
+    quote::quote! {
+        #vis fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            #(#checks)*
+            missing
+        }
+    }
+}
+
+// Доступ ко всем хранимым полям и список незаданных обязательных
+fn gen_accessors(fields: &[FieldInfo], pattern: Pattern, vis: &Visibility) -> TokenStream2 {
+    let accessors = stored_fields(fields).map(|info| gen_field_accessors(info, pattern, vis));
+    let missing_fields = gen_missing_fields(fields, vis);
+    quote::quote! {
+        #(#accessors)*
+
+        #missing_fields
+    }
+}
+
+// Итоговое значение необязательного поля в build() по хранимому в билдере Option.
+// Выражение по умолчанию вычисляется только если поле не задано и может
+// ссылаться на уже вычисленные поля, объявленные выше
+fn gen_optional_value(info: &FieldInfo, stored: TokenStream2) -> TokenStream2 {
+    match (&info.default, info.is_option) {
+        (Some(default), false) => quote::quote! {
+            match #stored {
+                ::core::option::Option::Some(__value) => __value,
+                ::core::option::Option::None => #default,
+            }
+        },
+        (Some(default), true) => quote::quote! {
+            match #stored {
+                ::core::option::Option::Some(__value) => ::core::option::Option::Some(__value),
+                ::core::option::Option::None => #default,
+            }
+        },
+        (None, _) => stored,
+    }
+}
+
+// Default самой структуры для #[builder(default)] над структурой
+fn gen_struct_default(
+    structure_name: &Ident,
+    generics: &Generics,
+    struct_attrs: &StructAttrs,
+) -> TokenStream2 {
+    let (_, ty_generics, _) = generics.split_for_impl();
+    match struct_attrs.default {
+        true => quote::quote! {
+            let __default = <#structure_name #ty_generics as ::core::default::Default>::default();
+        },
+        false => quote::quote! {},
+    }
+}
+
+// derive билдера: Debug всегда, Clone для immutable-сеттеров, которые
+// возвращают копию билдера, и всё из #[builder(derive(...))]
+fn gen_builder_derives(struct_attrs: &StructAttrs) -> TokenStream2 {
+    let mut derives = vec![quote::quote! { ::core::fmt::Debug }];
+    let mut builtin = vec!["Debug"];
+    if struct_attrs.pattern == Pattern::Immutable {
+        derives.push(quote::quote! { ::core::clone::Clone });
+        builtin.push("Clone");
+    }
+    // Default билдера и патча пишется руками; derive(Default) из прежних версий пропускаем
+    if !struct_attrs.typestate {
+        builtin.push("Default");
+    }
+    // повторный derive того же трейта дал бы конфликтующие реализации
+    let extra = struct_attrs.derives.iter().filter(|path| {
+        path.segments
+            .last()
+            .is_none_or(|segment| !builtin.iter().any(|name| segment.ident == name))
+    });
+    derives.extend(extra.map(|path| quote::quote! { #path }));
+
+    quote::quote! { #(#derives),* }
+}
+
+fn gen_builder_struct_code(
+    builder_name: &Ident,
+    struct_attrs: &StructAttrs,
+    generics: &Generics,
+    fields: &[FieldInfo],
+) -> TokenStream2 {
+    // все поля билдера хранятся как Option<T>, чтобы отличать незаданные
+    // вложенный билдер хранится как есть
+    let struct_fields = stored_fields(fields).map(|info| {
+        let name = &info.name;
+        let field_type = &info.type_;
+        match &info.sub_builder {
+            Some(sub_builder) => quote::quote! { #name: #sub_builder },
+            None => quote::quote! { #name: ::core::option::Option<#field_type> },
         }
     });
+    let field_names = stored_fields(fields).map(|info| &info.name);
+    let empty_values = stored_fields(fields).map(|info| match info.sub_builder {
+        Some(_) => quote::quote! { ::core::default::Default::default() },
+        None => quote::quote! { ::core::option::Option::None },
+    });
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let name = format_ident!("{}Builder", structure_name);
+    let derives = gen_builder_derives(struct_attrs);
+    let vis = &struct_attrs.vis;
 
+    // Default пишем руками: derive потребовал бы Default от всех параметров типа
     let generated_code = quote::quote! {
 
-    #[derive(Debug, Default)]
-    struct #name {
+    #[derive(#derives)]
+    #vis struct #builder_name #generics #where_clause {
             #(#struct_fields),*
       }
+
+    impl #impl_generics ::core::default::Default for #builder_name #ty_generics #where_clause {
+        fn default() -> Self {
+            #builder_name {
+                #(#field_names: #empty_values),*
+            }
+        }
+    }
     };
 
     generated_code
 }
 
-fn gen_impl_builder_code(fields: &HashMap<Ident, FieldInfo>) -> TokenStream2 {
-    let struct_fields_setters: Vec<_> = fields.iter().map(|(name, info)| {
-        let field_value = match info.is_mandatory {
-            true => quote::quote! { val },
-            false => quote::quote! { Some(val) }
-        };
-        let field_ident = &name;
-        let field_type = &info.type_;
+// Обратный путь из готовой структуры в билдер: From<S> забирает поля,
+// to_builder() клонирует их. Option-поле со значением None остаётся незаданным
+fn gen_round_trip_code(
+    structure_name: &Ident,
+    builder_name: &Ident,
+    generics: &Generics,
+    fields: &[FieldInfo],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let filled_builder = |source: fn(&Ident) -> TokenStream2| {
+        let stored = stored_fields(fields).map(|info| {
+            let name = &info.name;
+            let value = source(name);
+            match (&info.sub_builder, info.is_option) {
+                (Some(_), _) => quote::quote! { #name: ::core::convert::From::from(#value) },
+                (None, true) => quote::quote! { #name: #value },
+                (None, false) => quote::quote! { #name: ::core::option::Option::Some(#value) },
+            }
+        });
+        quote::quote! { #builder_name { #(#stored),* } }
+    };
+    let from_value = filled_builder(|name| quote::quote! { value.#name });
+    let to_builder = gen_to_builder(
+        structure_name,
+        generics,
+        fields,
+        quote::quote! { #builder_name #ty_generics },
+        filled_builder(|name| quote::quote! { ::core::clone::Clone::clone(&self.#name) }),
+    );
+
+    quote::quote! {
+        impl #impl_generics ::core::convert::From<#structure_name #ty_generics> for #builder_name #ty_generics #where_clause {
+            fn from(value: #structure_name #ty_generics) -> Self {
+                #from_value
+            }
+        }
+
+        #to_builder
+    }
+}
+
+// to_builder() клонирует поля, хранящиеся в билдере. Clone требуется только от
+// их типов, а не от всей структуры; for<'__a> не даёт границе стать
+// безусловной ошибкой для поля без Clone - тогда метод просто нельзя вызвать
+fn gen_to_builder(
+    structure_name: &Ident,
+    generics: &Generics,
+    fields: &[FieldInfo],
+    builder_type: TokenStream2,
+    filled_builder: TokenStream2,
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let field_types = stored_fields(fields).map(|info| &info.type_);
+
+    quote::quote! {
+        impl #impl_generics #structure_name #ty_generics #where_clause {
+            pub fn to_builder(&self) -> #builder_type
+            where
+                #(for<'__a> #field_types: ::core::clone::Clone),*
+            {
+                #filled_builder
+            }
+        }
+    }
+}
+
+// build() забирает билдер так же, как сеттеры
+fn gen_build_receiver(pattern: Pattern) -> TokenStream2 {
+    match pattern {
+        Pattern::Mutable => quote::quote! { &mut self },
+        Pattern::Owned => quote::quote! { self },
+        Pattern::Immutable => quote::quote! { &self },
+    }
+}
+
+// Начало build(): проверка обязательных полей, затем итоговые значения всех
+// полей в одноимённых переменных и их проверки validate
+fn gen_resolve_fields(
+    error_name: &Ident,
+    struct_attrs: &StructAttrs,
+    fields: &[FieldInfo],
+    struct_default: TokenStream2,
+) -> TokenStream2 {
+    // окружение читается только для полей, сеттер которых не вызывался
+    let env_lookups = fields.iter().filter_map(|info| {
+        let env = info.env.as_ref()?;
+        let name = &info.name;
+        let env_name = format_ident!("__env_{}", name);
+        let lookup = gen_env_lookup(info, env, error_name);
+        Some(quote::quote! {
+            let #env_name = match self.#name.is_some() {
+                true => ::core::option::Option::None,
+                false => #lookup,
+            };
+        })
+    });
+
+    // вложенные билдеры собираются до проверки обязательных полей, чтобы их
+    // незаданные поля попали в ту же ошибку
+    let sub_builders: Vec<_> = fields.iter().filter(|info| info.sub_builder.is_some()).collect();
+    let sub_builds = sub_builders.iter().map(|info| {
+        let sub_name = format_ident!("__sub_{}", info.name);
+        let built = gen_sub_builder_build(info, struct_attrs.pattern);
+        quote::quote! { let #sub_name = #built; }
+    });
+    let sub_errors = sub_builders.iter().map(|info| {
+        let sub_name = format_ident!("__sub_{}", info.name);
+        let field = field_name(&info.name);
+        quote::quote! {
+            if let ::core::result::Result::Err(err) = &#sub_name {
+                nested.extend(err.__nested_errors(#field));
+            }
+        }
+    });
 
+    // незаданные обязательные поля собираем все сразу, в порядке объявления
+    let missing_checks = fields.iter().filter(|info| info.is_mandatory).map(|info| {
+        let name = &info.name;
+        let field = field_name(name);
+        let unset = match info.env {
+            Some(_) => {
+                let env_name = format_ident!("__env_{}", name);
+                quote::quote! { self.#name.is_none() && #env_name.is_none() }
+            }
+            None => quote::quote! { self.#name.is_none() },
+        };
         quote::quote! {
-            fn #field_ident(&mut self, val: #field_type) -> &mut Self {
-                self.#field_ident = #field_value;
-                self
+            if #unset {
+                missing.push(#field);
             }
         }
-    }).collect();
+    });
 
-    let generated_code = quote::quote! {
+    // поле задано сеттером или окружением - для групп и requires
+    let is_set = |info: &FieldInfo| {
+        let name = &info.name;
+        match info.env {
+            Some(_) => {
+                let env_name = format_ident!("__env_{}", name);
+                quote::quote! { (self.#name.is_some() || #env_name.is_some()) }
+            }
+            None => quote::quote! { self.#name.is_some() },
+        }
+    };
+    let constraint_checks = gen_constraint_checks(error_name, fields, is_set);
 
-        impl CommandBuilder {
-                #(#struct_fields_setters) *
+    let field_lets = gen_field_lets(error_name, struct_attrs, fields);
+    let field_validation = gen_field_validation(error_name, fields);
+
+    // без обязательных полей в ошибке нет и варианта MissingFields
+    let missing_checks: Vec<_> = missing_checks.collect();
+    // если не собрался и вложенный билдер, все ошибки уходят в Nested
+    let nested_missing = match sub_builders.is_empty() {
+        true => TokenStream2::new(),
+        false => quote::quote! {
+            let mut nested = ::std::vec::Vec::new();
+            #(#sub_errors)*
+            if !nested.is_empty() {
+                let mut errors: ::std::vec::Vec<_> = missing
+                    .iter()
+                    .map(|field| (::std::string::ToString::to_string(field), ::std::string::ToString::to_string("missing")))
+                    .collect();
+                errors.extend(nested);
+                return ::core::result::Result::Err(#error_name::Nested(errors));
+            }
+        },
+    };
+    let missing_check = match missing_checks.is_empty() {
+        true => TokenStream2::new(),
+        false => quote::quote! {
+            let mut missing = ::std::vec::Vec::new();
+            #(#missing_checks)*
+            if !missing.is_empty() {
+                #nested_missing
+                return ::core::result::Result::Err(#error_name::MissingFields(missing));
+            }
+        },
+    };
+
+    quote::quote! {
+        #(#env_lookups)*
+        #(#sub_builds)*
+
+        #missing_check
+        #constraint_checks
+
+        #struct_default
+        #field_lets
+        #field_validation
+    }
+}
+
+// Итоговые значения полей в одноимённых переменных, вычисляемые - после остальных.
+// owned-билдер отдаёт поля без клонирования
+fn gen_field_lets(error_name: &Ident, struct_attrs: &StructAttrs, fields: &[FieldInfo]) -> TokenStream2 {
+    let pattern = struct_attrs.pattern;
+    let ordered = build_order(fields);
+    let field_names = ordered.iter().map(|info| &info.name);
+    let field_values = ordered.iter().map(|info| {
+        let name = &info.name;
+        if let Some(compute) = &info.compute {
+            return quote::quote! { #compute };
+        }
+        if info.sub_builder.is_some() {
+            return gen_sub_builder_value(info, error_name);
+        }
+        let mut stored = match pattern {
+            Pattern::Owned => quote::quote! { self.#name },
+            Pattern::Mutable | Pattern::Immutable => {
+                quote::quote! { ::core::clone::Clone::clone(&self.#name) }
+            }
+        };
+        if info.env.is_some() {
+            let env_name = format_ident!("__env_{}", name);
+            stored = quote::quote! { ::core::option::Option::or(#stored, #env_name) };
+        }
+        match info.is_mandatory {
+            true => quote::quote! { ::core::option::Option::unwrap(#stored) },
+            false => gen_optional_value(info, stored),
+        }
+    });
+    quote::quote! {
+        #(let #field_names = #field_values;)*
+    }
+}
+
+fn gen_impl_builder_code(
+    structure_name: &Ident,
+    builder_name: &Ident,
+    error_name: &Ident,
+    struct_attrs: &StructAttrs,
+    generics: &Generics,
+    fields: &[FieldInfo],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let pattern = struct_attrs.pattern;
+    let struct_fields_setters: Vec<_> = match struct_attrs.is_const {
+        true => vec![gen_const_setters(struct_attrs, fields)],
+        false => stored_fields(fields)
+            .map(|info| gen_field_setters(info, pattern, &struct_attrs.vis))
+            .collect(),
+    };
+
+    let field_names: Vec<_> = fields.iter().map(|info| &info.name).collect();
+    let struct_default = gen_struct_default(structure_name, generics, struct_attrs);
+    let build_receiver = gen_build_receiver(pattern);
+
+    let from_env = gen_from_env(error_name, struct_attrs, fields);
+    let accessors = gen_accessors(fields, pattern, &struct_attrs.vis);
+    let fields_table = gen_fields_table(builder_name, &struct_attrs.vis, fields);
+    let set_by_name = gen_set_by_name(error_name, &struct_attrs.vis, fields);
+    let vis = &struct_attrs.vis;
+    let build_fn = &struct_attrs.build_fn;
+
+    // без возможных ошибок build() возвращает структуру как есть,
+    // const build() вместо ошибки паникует
+    let build_method = match (struct_attrs.is_const, is_fallible(struct_attrs, fields)) {
+        (true, _) => {
+            let const_build = gen_const_build(structure_name, builder_name, struct_attrs, generics, fields);
+            let resolve_fields = gen_resolve_fields(error_name, struct_attrs, fields, struct_default);
+            quote::quote! {
+                #const_build
+
+                // вложенному билдеру нужна ошибка, а не паника: проверки как у обычного build()
+                #[doc(hidden)]
+                #vis fn __try_build(#build_receiver) -> ::core::result::Result<#structure_name #ty_generics, #error_name> {
+                    #resolve_fields
 
-                fn build(&mut self) -> Result<Command, Box<dyn Error>> {
-                    Ok(
-                        Command {
-                            executable: self.executable.clone(),
-                            args: self.args.clone(),
-                            env: self.env.clone(),
-                            current_dir: self.current_dir.clone()
+                    ::core::result::Result::Ok(#structure_name {
+                        #(#field_names),*
                     })
                 }
-        };
+            }
+        }
+        (false, true) => {
+            let resolve_fields = gen_resolve_fields(error_name, struct_attrs, fields, struct_default);
+            let struct_validation = gen_struct_validation(error_name, struct_attrs);
+            quote::quote! {
+                #vis fn #build_fn(#build_receiver) -> ::core::result::Result<#structure_name #ty_generics, #error_name> {
+                    #resolve_fields
+
+                    let __built = #structure_name {
+                        #(#field_names),*
+                    };
+                    #struct_validation
+                    ::core::result::Result::Ok(__built)
+                }
+
+                #[doc(hidden)]
+                #vis fn __try_build(#build_receiver) -> ::core::result::Result<#structure_name #ty_generics, #error_name> {
+                    self.#build_fn()
+                }
+            }
+        }
+        (false, false) => {
+            let field_lets = gen_field_lets(error_name, struct_attrs, fields);
+            quote::quote! {
+                #vis fn #build_fn(#build_receiver) -> #structure_name #ty_generics {
+                    #struct_default
+                    #field_lets
+
+                    #structure_name {
+                        #(#field_names),*
+                    }
+                }
 
+                #[doc(hidden)]
+                #vis fn __try_build(#build_receiver) -> ::core::result::Result<#structure_name #ty_generics, #error_name> {
+                    ::core::result::Result::Ok(self.#build_fn())
+                }
+            }
+        }
     };
-    generated_code
-}
 
-#[proc_macro_derive(Builder)]
-pub fn derive(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
+    let generated_code = quote::quote! {
 
-    let input_fields = extract_fields(&input);
-    // данные Опциональности и Типов полей
-    let fields = get_fields_info(&input_fields);
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+                #(#struct_fields_setters) *
 
-    // генерация билд-структуры по прототипу(теже поля и типы полей) вызывающей структуры
-    let builder_struct_code = gen_builder_struct_code(&input.ident, &input_fields);
+                #accessors
 
-    // Генерация кода функций-сеттеров в зависимости от опциональности полей
-    let impl_command_builder_code = gen_impl_builder_code(&fields);
+                #fields_table
 
-    let expanded = quote::quote! {
+                #set_by_name
 
-        use std::result::Result;
-        use std::error::Error;
-        use std::fmt::{Display, Formatter, Result as FmtResult};
+                #from_env
 
-        #builder_struct_code
+                #build_method
+        }
+
+    };
+    generated_code
+}
 
-        impl Command {
-            pub fn builder() -> CommandBuilder {
-                CommandBuilder::default()
+// Значение поля из переменной окружения: Option<T>, None если переменной нет.
+// Ошибки разбора и не-UTF-8 значения возвращаются как InvalidField
+fn gen_env_lookup(info: &FieldInfo, env: &LitStr, error_name: &Ident) -> TokenStream2 {
+    let field_type = &info.type_;
+    let field = field_name(&info.name);
+    quote::quote! {
+        match ::std::env::var(#env) {
+            ::core::result::Result::Ok(raw) => {
+                match <#field_type as ::core::str::FromStr>::from_str(&raw) {
+                    ::core::result::Result::Ok(value) => ::core::option::Option::Some(value),
+                    ::core::result::Result::Err(err) => {
+                        return ::core::result::Result::Err(#error_name::InvalidField {
+                            field: #field,
+                            message: ::std::format!("{}: {}", #env, err),
+                        });
+                    }
+                }
+            }
+            ::core::result::Result::Err(::std::env::VarError::NotPresent) => {
+                ::core::option::Option::None
+            }
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(#error_name::InvalidField {
+                    field: #field,
+                    message: ::std::format!("{}: {}", #env, err),
+                });
             }
         }
+    }
+}
+
+// Билдер, заполненный из окружения: поля с #[builder(env = "...")], для
+// которых переменная задана
+fn gen_from_env(error_name: &Ident, struct_attrs: &StructAttrs, fields: &[FieldInfo]) -> TokenStream2 {
+    let lookups: Vec<_> = fields
+        .iter()
+        .filter_map(|info| {
+            let env = info.env.as_ref()?;
+            let name = &info.name;
+            let lookup = gen_env_lookup(info, env, error_name);
+            Some(quote::quote! { __builder.#name = #lookup; })
+        })
+        .collect();
+    if lookups.is_empty() {
+        return TokenStream2::new();
+    }
+
+    let vis = &struct_attrs.vis;
+    quote::quote! {
+        #vis fn from_env() -> ::core::result::Result<Self, #error_name> {
+            let mut __builder = <Self as ::core::default::Default>::default();
+            #(#lookups)*
+            ::core::result::Result::Ok(__builder)
+        }
+    }
+}
 
-        #[derive(Debug)]
-        pub struct BuilderError {
-            pub msg: String
+// Сборка вложенного билдера в Result - до проверки обязательных полей родителя
+fn gen_sub_builder_build(info: &FieldInfo, pattern: Pattern) -> TokenStream2 {
+    let name = &info.name;
+    match pattern {
+        Pattern::Mutable => quote::quote! { self.#name.__try_build() },
+        Pattern::Owned => quote::quote! { { let mut __sub_builder = self.#name; __sub_builder.__try_build() } },
+        Pattern::Immutable => quote::quote! { ::core::clone::Clone::clone(&self.#name).__try_build() },
+    }
+}
+
+// Значение вложенного билдера, собранного в gen_sub_builder_build. Его ошибки
+// получают префикс с именем поля: tls.cert: missing
+fn gen_sub_builder_value(info: &FieldInfo, error_name: &Ident) -> TokenStream2 {
+    let sub_name = format_ident!("__sub_{}", info.name);
+    let field = field_name(&info.name);
+    quote::quote! {
+        match #sub_name {
+            ::core::result::Result::Ok(__value) => __value,
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(#error_name::Nested(err.__nested_errors(#field)));
+            }
         }
+    }
+}
+
+// Проверки #[builder(validate = "...")] над итоговыми значениями полей,
+// значения уже лежат в одноимённых переменных
+fn gen_field_validation(error_name: &Ident, fields: &[FieldInfo]) -> TokenStream2 {
+    let checks = fields.iter().filter_map(|info| {
+        let validate = info.validate.as_ref()?;
+        let name = &info.name;
+        let field = field_name(name);
+        Some(quote::quote! {
+            if let ::core::result::Result::Err(err) = #validate(&#name) {
+                return ::core::result::Result::Err(#error_name::InvalidField {
+                    field: #field,
+                    message: ::std::string::ToString::to_string(&err),
+                });
+            }
+        })
+    });
+    quote::quote! { #(#checks)* }
+}
+
+// Проверка собранной структуры, она лежит в __built
+fn gen_struct_validation(error_name: &Ident, struct_attrs: &StructAttrs) -> TokenStream2 {
+    match &struct_attrs.validate {
+        Some(validate) => quote::quote! {
+            if let ::core::result::Result::Err(err) = #validate(&__built) {
+                return ::core::result::Result::Err(#error_name::Invalid(
+                    ::std::string::ToString::to_string(&err),
+                ));
+            }
+        },
+        None => TokenStream2::new(),
+    }
+}
+
+// Может ли build() обычного билдера завершиться ошибкой. Без обязательных
+// полей, проверок, окружения, вложенных билдеров и ограничений он собирает
+// структуру всегда, если #[builder(fallible)] не оставляет Result ради API
+fn is_fallible(struct_attrs: &StructAttrs, fields: &[FieldInfo]) -> bool {
+    struct_attrs.fallible
+        || has_validation(struct_attrs, fields)
+        || fields.iter().any(|info| {
+            info.is_mandatory
+                || info.env.is_some()
+                || info.sub_builder.is_some()
+                || info.group.is_some()
+                || !info.requires.is_empty()
+        })
+}
+
+// Есть ли проверки, которые делают build() typestate-билдера fallible
+fn has_validation(struct_attrs: &StructAttrs, fields: &[FieldInfo]) -> bool {
+    struct_attrs.validate.is_some() || fields.iter().any(|info| info.validate.is_some())
+}
+
+// Варианты ошибки, которые билдер структуры может вернуть
+struct ErrorVariants {
+    missing_fields: bool,
+    invalid_field: bool,
+    invalid: bool,
+    group_conflict: bool,
+    group_unset: bool,
+    missing_required: bool,
+    unknown_field: bool,
+    nested: bool,
+}
+
+// У typestate-билдера незаданные поля и ограничения - ошибки компиляции,
+// ошибкой build() бывают только проверки validate. Обычный билдер ещё
+// разбирает значения в set_by_name(), а незнакомое имя - UnknownField
+fn error_variants(struct_attrs: &StructAttrs, fields: &[FieldInfo]) -> ErrorVariants {
+    let any = |check: fn(&FieldInfo) -> bool| fields.iter().any(check);
+    let field_validation = any(|info| info.validate.is_some());
+    if struct_attrs.typestate {
+        return ErrorVariants {
+            missing_fields: false,
+            invalid_field: field_validation,
+            invalid: struct_attrs.validate.is_some(),
+            group_conflict: false,
+            group_unset: false,
+            missing_required: false,
+            unknown_field: false,
+            nested: false,
+        };
+    }
+    ErrorVariants {
+        missing_fields: any(|info| info.is_mandatory),
+        invalid_field: field_validation
+            || any(|info| info.env.is_some())
+            || stored_fields(fields).next().is_some(),
+        invalid: struct_attrs.validate.is_some(),
+        group_conflict: any(|info| info.group.is_some()),
+        group_unset: any(|info| {
+            matches!(&info.group, Some(group) if group.kind == attrs::GroupKind::ExactlyOne)
+        }),
+        missing_required: any(|info| !info.requires.is_empty()),
+        unknown_field: true,
+        nested: any(|info| info.sub_builder.is_some()),
+    }
+}
+
+// Ошибка build() - своя для каждой структуры, только с теми вариантами,
+// которые её билдер может вернуть. non_exhaustive: новые опции добавляют варианты
+fn gen_error_code(error_name: &Ident, vis: &Visibility, variants: &ErrorVariants) -> TokenStream2 {
+    // вариант, его пути в __nested_errors и текст для Display
+    let mut decls = Vec::new();
+    let mut nested_arms = Vec::new();
+    let mut display_arms = Vec::new();
+    let to_string = quote::quote! { ::std::string::ToString::to_string };
+
+    if variants.missing_fields {
+        decls.push(quote::quote! {
+            // обязательные поля, которые не были заданы
+            MissingFields(::std::vec::Vec<&'static str>),
+        });
+        nested_arms.push(quote::quote! {
+            #error_name::MissingFields(fields) => fields
+                .iter()
+                .map(|inner| (path(inner), #to_string("missing")))
+                .collect(),
+        });
+        display_arms.push(quote::quote! {
+            #error_name::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+        });
+    }
+    if variants.invalid_field {
+        decls.push(quote::quote! {
+            // значение поля не прошло проверку или разбор
+            InvalidField { field: &'static str, message: ::std::string::String },
+        });
+        nested_arms.push(quote::quote! {
+            #error_name::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+        });
+        display_arms.push(quote::quote! {
+            #error_name::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+        });
+    }
+    if variants.invalid {
+        decls.push(quote::quote! {
+            // собранная структура не прошла проверку
+            Invalid(::std::string::String),
+        });
+        nested_arms.push(quote::quote! {
+            #error_name::Invalid(message) => {
+                ::std::vec![(path(""), ::core::clone::Clone::clone(message))]
+            }
+        });
+        display_arms.push(quote::quote! {
+            #error_name::Invalid(message) => {
+                ::core::write!(f, "validation failed: {}", message)
+            }
+        });
+    }
+    if variants.group_conflict {
+        decls.push(quote::quote! {
+            // задано больше одного поля группы
+            GroupConflict { group: &'static str, fields: ::std::vec::Vec<&'static str> },
+        });
+        nested_arms.push(quote::quote! {
+            #error_name::GroupConflict { .. } => ::std::vec![(path(""), #to_string(self))],
+        });
+        display_arms.push(quote::quote! {
+            #error_name::GroupConflict { group, fields } => {
+                ::core::write!(
+                    f,
+                    "at most one field of group `{}` can be set, got: {}",
+                    group,
+                    fields.join(", "),
+                )
+            }
+        });
+    }
+    if variants.group_unset {
+        decls.push(quote::quote! {
+            // не задано ни одно поле группы exactly_one
+            GroupUnset { group: &'static str, fields: ::std::vec::Vec<&'static str> },
+        });
+        nested_arms.push(quote::quote! {
+            #error_name::GroupUnset { .. } => ::std::vec![(path(""), #to_string(self))],
+        });
+        display_arms.push(quote::quote! {
+            #error_name::GroupUnset { group, fields } => {
+                ::core::write!(
+                    f,
+                    "exactly one field of group `{}` must be set: {}",
+                    group,
+                    fields.join(", "),
+                )
+            }
+        });
+    }
+    if variants.missing_required {
+        decls.push(quote::quote! {
+            // задано поле, а поле из его requires - нет
+            MissingRequired { field: &'static str, required: &'static str },
+        });
+        nested_arms.push(quote::quote! {
+            #error_name::MissingRequired { .. } => ::std::vec![(path(""), #to_string(self))],
+        });
+        display_arms.push(quote::quote! {
+            #error_name::MissingRequired { field, required } => {
+                ::core::write!(f, "field `{}` requires `{}` to be set", field, required)
+            }
+        });
+    }
+    if variants.unknown_field {
+        decls.push(quote::quote! {
+            // set_by_name() с именем, которого нет среди полей
+            UnknownField(::std::string::String),
+        });
+        nested_arms.push(quote::quote! {
+            #error_name::UnknownField(_) => ::std::vec![(path(""), #to_string(self))],
+        });
+        display_arms.push(quote::quote! {
+            #error_name::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        });
+    }
+    if variants.nested {
+        decls.push(quote::quote! {
+            // ошибки вложенных билдеров: путь поля и сообщение
+            Nested(::std::vec::Vec<(::std::string::String, ::std::string::String)>),
+        });
+        nested_arms.push(quote::quote! {
+            #error_name::Nested(errors) => errors
+                .iter()
+                .map(|(inner, message)| (path(inner), ::core::clone::Clone::clone(message)))
+                .collect(),
+        });
+        display_arms.push(quote::quote! {
+            #error_name::Nested(errors) => {
+                for (i, (path, message)) in errors.iter().enumerate() {
+                    if i > 0 {
+                        ::core::write!(f, ", ")?;
+                    }
+                    ::core::write!(f, "{}: {}", path, message)?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        });
+    }
 
-        impl Display for BuilderError {
-            fn fmt(&self, f: &mut Formatter) -> FmtResult {
-                write!(f, "{}", self.msg)
+    // без вариантов (typestate с fallible) сопоставлять можно только *self
+    let nested_body = match decls.is_empty() {
+        true => quote::quote! { match *self {} },
+        false => quote::quote! {
+            let path = |inner: &str| match inner.is_empty() {
+                true => ::std::string::ToString::to_string(field),
+                false => ::std::format!("{}.{}", field, inner),
+            };
+            match self {
+                #(#nested_arms)*
             }
+        },
+    };
+    let display_scrutinee = match decls.is_empty() {
+        true => quote::quote! { *self },
+        false => quote::quote! { self },
+    };
+
+    quote::quote! {
+        #[derive(
+            ::core::fmt::Debug,
+            ::core::clone::Clone,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+        )]
+        #[non_exhaustive]
+        #vis enum #error_name {
+            #(#decls)*
         }
 
-        impl Error for BuilderError {
-            fn description(&self) -> &str {
-                &self.msg
+        impl #error_name {
+            // ошибки с путями полей для ошибки родительского билдера
+            #[doc(hidden)]
+            #vis fn __nested_errors(&self, field: &str) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+                #nested_body
             }
         }
 
-        impl From<String> for BuilderError {
-            fn from(err: String) -> BuilderError {
-                BuilderError { msg: err }
+        impl ::core::fmt::Display for #error_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                match #display_scrutinee {
+                    #(#display_arms)*
+                }
             }
         }
 
-        #impl_command_builder_code
+        impl ::std::error::Error for #error_name {}
+    }
+}
 
+#[proc_macro_derive(Builder, attributes(builder))]
+pub fn derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // любая ошибка разбора превращается в compile_error! с привязкой к месту в коде
+    expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let struct_attrs = parse_struct_attrs(input)?;
+
+    let input_fields = extract_fields(input)?;
+    // данные Опциональности и Типов полей
+    let fields = get_fields_info(input_fields, &struct_attrs)?;
+
+    let structure_name = &input.ident;
+    let builder_name = &struct_attrs.name;
+    let error_name = format_ident!("{}Error", builder_name);
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let builder_code = if struct_attrs.typestate {
+        let typestate_code = gen_typestate_code(
+            structure_name,
+            builder_name,
+            &error_name,
+            &struct_attrs,
+            generics,
+            &fields,
+        );
+        // без проверок build() typestate-билдера не может завершиться ошибкой
+        let error_code = match struct_attrs.fallible || has_validation(&struct_attrs, &fields) {
+            true => gen_error_code(&error_name, &struct_attrs.vis, &error_variants(&struct_attrs, &fields)),
+            false => TokenStream2::new(),
+        };
+
+        quote::quote! {
+            #typestate_code
+
+            #error_code
+        }
+    } else {
+        // генерация билд-структуры по прототипу(теже поля и типы полей) вызывающей структуры
+        let builder_struct_code = gen_builder_struct_code(builder_name, &struct_attrs, generics, &fields);
+
+        // Генерация кода функций-сеттеров в зависимости от опциональности полей
+        let impl_command_builder_code = gen_impl_builder_code(
+            structure_name,
+            builder_name,
+            &error_name,
+            &struct_attrs,
+            generics,
+            &fields,
+        );
+        let error_code = gen_error_code(&error_name, &struct_attrs.vis, &error_variants(&struct_attrs, &fields));
+        let round_trip_code = gen_round_trip_code(structure_name, builder_name, generics, &fields);
+        let builder_fn = match struct_attrs.is_const {
+            true => gen_const_builder_fn(builder_name, generics, &fields),
+            false => quote::quote! {
+                pub fn builder() -> #builder_name #ty_generics {
+                    <#builder_name #ty_generics as ::core::default::Default>::default()
+                }
+            },
+        };
+
+        quote::quote! {
+            #builder_struct_code
+
+            impl #impl_generics #structure_name #ty_generics #where_clause {
+                #builder_fn
+            }
+
+            #impl_command_builder_code
+
+            #round_trip_code
+
+            #error_code
+        }
+    };
+
+    let patch_code = match struct_attrs.patch {
+        true => gen_patch_code(structure_name, &struct_attrs, generics, &fields),
+        false => TokenStream2::new(),
     };
 
-    TokenStream::from(expanded)
+    let field_meta_code = gen_field_meta_code(builder_name, &struct_attrs.vis);
+
+    Ok(quote::quote! {
+        #builder_code
+
+        #field_meta_code
+
+        #patch_code
+    })
 }
 
+// Билдер для функции: #[builder] fn spawn(...) -> spawn_builder()...call()
+#[proc_macro_attribute]
+pub fn builder(args: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as ItemFn);
+
+    function::expand_fn(args.into(), item)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,81 @@
+use crate::attrs::StructAttrs;
+use crate::{gen_builder_derives, stored_fields, FieldInfo};
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use syn::{Generics, Ident};
+
+// Частичное обновление структуры: {Name}Patch с полями Option<FieldType>.
+// None - поле не меняется, Some(v) - заменяется на v; у Option-полей
+// Some(None) сбрасывает значение. Поля без сеттера (skip, compute) в патч
+// не входят. Патч получает те же derive, что и билдер
+pub fn gen_patch_code(
+    structure_name: &Ident,
+    struct_attrs: &StructAttrs,
+    generics: &Generics,
+    fields: &[FieldInfo],
+) -> TokenStream2 {
+    let patch_name = format_ident!("{}Patch", structure_name);
+    let vis = &struct_attrs.vis;
+    let derives = gen_builder_derives(struct_attrs);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let names: Vec<_> = stored_fields(fields).map(|info| &info.name).collect();
+    // тип поля в самой структуре
+    let types: Vec<_> = stored_fields(fields)
+        .map(|info| {
+            let field_type = &info.type_;
+            match info.is_option {
+                true => quote::quote! { ::core::option::Option<#field_type> },
+                false => quote::quote! { #field_type },
+            }
+        })
+        .collect();
+
+    quote::quote! {
+        #[derive(#derives)]
+        #vis struct #patch_name #generics #where_clause {
+            #(#vis #names: ::core::option::Option<#types>),*
+        }
+
+        impl #impl_generics ::core::default::Default for #patch_name #ty_generics #where_clause {
+            fn default() -> Self {
+                #patch_name {
+                    #(#names: ::core::option::Option::None),*
+                }
+            }
+        }
+
+        impl #impl_generics #patch_name #ty_generics #where_clause {
+            // слои конфигурации: заданное в other важнее заданного в self
+            #vis fn merge(self, other: Self) -> Self {
+                #patch_name {
+                    #(#names: ::core::option::Option::or(other.#names, self.#names)),*
+                }
+            }
+        }
+
+        impl #impl_generics #structure_name #ty_generics #where_clause {
+            #vis fn apply(&mut self, patch: #patch_name #ty_generics) {
+                #(
+                    if let ::core::option::Option::Some(value) = patch.#names {
+                        self.#names = value;
+                    }
+                )*
+            }
+
+            // патч, который превращает self в other
+            #vis fn diff(&self, other: &Self) -> #patch_name #ty_generics
+            where
+                #(for<'__a> #types: ::core::cmp::PartialEq + ::core::clone::Clone),*
+            {
+                #patch_name {
+                    #(#names: match self.#names == other.#names {
+                        true => ::core::option::Option::None,
+                        false => ::core::option::Option::Some(::core::clone::Clone::clone(&other.#names)),
+                    }),*
+                }
+            }
+        }
+    }
+}
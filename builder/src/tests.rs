@@ -0,0 +1,137 @@
+// Снапшоты раскрытия derive(Builder) и #[builder] над функциями для каждого
+// тест-кейса из tests/*.rs.
+// Раскрытие должно совпадать с tests/expand/<имя>.expanded.rs байт в байт,
+// SNAPSHOTS=overwrite перезаписывает снапшоты.
+
+use crate::expand;
+use crate::function::expand_fn;
+
+use std::fs;
+use std::path::Path;
+use syn::punctuated::Punctuated;
+use syn::{DeriveInput, Item, ItemFn, Meta, Token};
+
+fn derives_builder(input: &DeriveInput) -> bool {
+    input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .any(|paths| paths.iter().any(|path| path.is_ident("Builder")))
+}
+
+// аргументы #[builder(...)] над функцией, атрибут макрос получает без него самого
+fn builder_fn_args(item: &mut ItemFn) -> Option<proc_macro2::TokenStream> {
+    let position = item.attrs.iter().position(|attr| attr.path().is_ident("builder"))?;
+    match item.attrs.remove(position).meta {
+        Meta::List(list) => Some(list.tokens),
+        _ => Some(proc_macro2::TokenStream::new()),
+    }
+}
+
+// раскрытия derive(Builder) и #[builder] среди items, в порядке следования;
+// содержимое mod раскрывается внутри одноимённого модуля
+fn expand_items(path: &Path, items: Vec<Item>) -> Vec<Item> {
+    let mut expanded_items = Vec::new();
+    for item in items {
+        let expand_item: Box<dyn Fn() -> syn::Result<proc_macro2::TokenStream>> = match item {
+            Item::Fn(mut item) => match builder_fn_args(&mut item) {
+                Some(args) => Box::new(move || expand_fn(args.clone(), item.clone())),
+                None => continue,
+            },
+            Item::Mod(mut item) => {
+                let Some((brace, content)) = item.content.take() else {
+                    continue;
+                };
+                let content = expand_items(path, content);
+                if !content.is_empty() {
+                    item.attrs.clear();
+                    item.content = Some((brace, content));
+                    expanded_items.push(Item::Mod(item));
+                }
+                continue;
+            }
+            item => {
+                let input: DeriveInput = match item {
+                    Item::Struct(item) => item.into(),
+                    Item::Enum(item) => item.into(),
+                    Item::Union(item) => item.into(),
+                    _ => continue,
+                };
+                if !derives_builder(&input) {
+                    continue;
+                }
+                Box::new(move || expand(&input))
+            }
+        };
+
+        let expanded = expand_item().unwrap_or_else(|err| err.to_compile_error());
+        // два раскрытия одного входа обязаны совпадать
+        let again = expand_item().unwrap_or_else(|err| err.to_compile_error());
+        assert_eq!(expanded.to_string(), again.to_string(), "{}", path.display());
+
+        expanded_items.extend(syn::parse2::<syn::File>(expanded).unwrap().items);
+    }
+    expanded_items
+}
+
+// раскрытие всего файла; None, если в нём нет ни одного билдера
+fn expand_file(path: &Path) -> Option<String> {
+    let source = fs::read_to_string(path).unwrap();
+    let file = syn::parse_file(&source).unwrap();
+
+    let items = expand_items(path, file.items);
+    if items.is_empty() {
+        return None;
+    }
+    Some(prettyplease::unparse(&syn::File {
+        shebang: None,
+        attrs: Vec::new(),
+        items,
+    }))
+}
+
+#[test]
+fn expand_snapshots() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let overwrite = std::env::var("SNAPSHOTS").as_deref() == Ok("overwrite");
+
+    let mut cases: Vec<_> = fs::read_dir(root.join("tests"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    cases.sort();
+
+    let mut failures = Vec::new();
+    for case in cases {
+        // тест-кейс билдера без раскрытия - значит, билдер не нашёлся
+        let Some(expanded) = expand_file(&case) else {
+            if fs::read_to_string(&case).unwrap().contains("derive_builder::") {
+                failures.push(format!("{}: no builder found to expand", case.display()));
+            }
+            continue;
+        };
+
+        let stem = case.file_stem().unwrap().to_str().unwrap();
+        let snapshot = root.join("tests/expand").join(format!("{}.expanded.rs", stem));
+        match fs::read_to_string(&snapshot) {
+            Ok(expected) if expected == expanded => {}
+            Ok(_) | Err(_) if overwrite => {
+                fs::create_dir_all(snapshot.parent().unwrap()).unwrap();
+                fs::write(&snapshot, expanded).unwrap();
+            }
+            Ok(_) => failures.push(format!("{}: expansion changed", snapshot.display())),
+            Err(_) => failures.push(format!("{}: snapshot is missing", snapshot.display())),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{}\nrerun with SNAPSHOTS=overwrite to update",
+        failures.join("\n"),
+    );
+}
@@ -0,0 +1,493 @@
+use crate::attrs::{GroupKind, StructAttrs};
+use crate::constraints::{groups, is_constrained};
+use crate::dynamic::gen_fields_table;
+use crate::utils::field_name;
+use crate::{
+    build_order, gen_builder_derives, gen_field_accessors, gen_field_setters, gen_field_validation, gen_optional_value, gen_struct_default,
+    gen_struct_validation, gen_to_builder, has_validation, stored_fields, FieldInfo,
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use syn::{GenericParam, Generics, Ident};
+
+// Typestate-билдер: каждое обязательное поле - параметр типа билдера.
+// Незаданное поле имеет тип `()`, заданное - `(T,)`. Сеттер обязательного поля
+// возвращает билдер с другим параметром, а build() требует, чтобы все параметры
+// реализовывали трейт "поле задано" - иначе ошибка компиляции с именем поля.
+// Поля из групп и requires тоже отслеживаются параметрами типа, а сами
+// ограничения - трейты над кортежами их состояний, так что нарушение
+// ограничения - тоже ошибка компиляции.
+// С проверками validate build() возвращает Result с ошибкой билдера.
+pub fn gen_typestate_code(
+    structure_name: &Ident,
+    builder_name: &Ident,
+    error_name: &Ident,
+    struct_attrs: &StructAttrs,
+    generics: &Generics,
+    fields: &[FieldInfo],
+) -> TokenStream2 {
+    // параметры типа идут в порядке объявления полей - они видны в сообщениях об ошибках
+    let ordered: Vec<_> = stored_fields(fields).map(|info| (&info.name, info)).collect();
+    let is_tracked = |info: &FieldInfo| info.is_mandatory || is_constrained(info, fields);
+    let tracked: Vec<_> = ordered.iter().filter(|(_, info)| is_tracked(info)).collect();
+    let optional: Vec<_> = ordered.iter().filter(|(_, info)| !is_tracked(info)).collect();
+    let mandatory: Vec<_> = tracked.iter().filter(|(_, info)| info.is_mandatory).collect();
+
+    let vis = &struct_attrs.vis;
+    let build_fn = &struct_attrs.build_fn;
+    let derives = gen_builder_derives(struct_attrs);
+
+    let state_params: Vec<_> = tracked
+        .iter()
+        .map(|(name, _)| format_ident!("__{}", name))
+        .collect();
+    let tracked_names: Vec<_> = tracked.iter().map(|(name, _)| *name).collect();
+    let state_param = |name: &Ident| format_ident!("__{}", name);
+    let maybe_set_trait = format_ident!("{}_maybe_set", builder_name);
+    let optional_names: Vec<_> = optional.iter().map(|(name, _)| *name).collect();
+    let optional_types: Vec<_> = optional.iter().map(|(_, info)| &info.type_).collect();
+
+    // параметры состояния добавляются после собственных параметров структуры
+    let mut decl_generics = generics.clone();
+    let mut builder_generics = generics.clone();
+    for param in &state_params {
+        decl_generics.params.push(syn::parse_quote! { #param = () });
+        builder_generics.params.push(syn::parse_quote! { #param });
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (builder_impl_generics, builder_ty_generics, _) = builder_generics.split_for_impl();
+
+    // аргументы собственных параметров структуры для явной записи типа билдера
+    let input_args: Vec<_> = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                quote::quote! { #lifetime }
+            }
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                quote::quote! { #ident }
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                quote::quote! { #ident }
+            }
+        })
+        .collect();
+
+    // сеттеры отслеживаемых полей: меняют свой параметр типа на (T,)
+    let tracked_setters = tracked.iter().enumerate().map(|(i, (name, info))| {
+        let field_type = &info.type_;
+        let result_params = state_params.iter().enumerate().map(|(j, param)| {
+            if i == j {
+                quote::quote! { (#field_type,) }
+            } else {
+                quote::quote! { #param }
+            }
+        });
+        let (param_type, value) = match info.setter_into {
+            true => (
+                quote::quote! { impl ::core::convert::Into<#field_type> },
+                quote::quote! { ::core::convert::Into::into(val) },
+            ),
+            false => (quote::quote! { #field_type }, quote::quote! { val }),
+        };
+        let moved_fields = tracked_names.iter().map(|other| {
+            if other == name {
+                quote::quote! { #other: (#value,) }
+            } else {
+                quote::quote! { #other: self.#other }
+            }
+        });
+
+        quote::quote! {
+            #vis fn #name(self, val: #param_type) -> #builder_name<#(#input_args,)* #(#result_params),*> {
+                #builder_name {
+                    #(#moved_fields,)*
+                    #(#optional_names: self.#optional_names,)*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+    });
+
+    // сеттеры опциональных полей тип билдера не меняют
+    let optional_setters = optional
+        .iter()
+        .map(|(_, info)| gen_field_setters(info, struct_attrs.pattern, vis));
+    let optional_accessors = optional
+        .iter()
+        .map(|(_, info)| gen_field_accessors(info, struct_attrs.pattern, vis));
+
+    // доступ к отслеживаемым полям в любом состоянии; clear_x() возвращает
+    // билдер, в котором поле снова не задано
+    let tracked_accessors = tracked.iter().enumerate().map(|(i, (name, info))| {
+        let field_type = &info.type_;
+        let ref_name = format_ident!("{}_ref", name);
+        let is_set_name = format_ident!("is_{}_set", name);
+        let clear_name = format_ident!("clear_{}", name);
+        let result_params = state_params.iter().enumerate().map(|(j, param)| match i == j {
+            true => quote::quote! { () },
+            false => quote::quote! { #param },
+        });
+        let moved_fields = tracked_names.iter().map(|other| match other == name {
+            true => quote::quote! { #other: () },
+            false => quote::quote! { #other: self.#other },
+        });
+        quote::quote! {
+            #vis fn #ref_name(&self) -> ::core::option::Option<&#field_type> {
+                #maybe_set_trait::<#field_type>::value_ref(&self.#name)
+            }
+
+            #vis fn #is_set_name(&self) -> bool {
+                ::core::option::Option::is_some(&self.#ref_name())
+            }
+
+            #vis fn #clear_name(self) -> #builder_name<#(#input_args,)* #(#result_params),*> {
+                #builder_name {
+                    #(#moved_fields,)*
+                    #(#optional_names: self.#optional_names,)*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+    });
+    let missing_checks = mandatory.iter().map(|(name, _)| {
+        let is_set_name = format_ident!("is_{}_set", name);
+        let field = field_name(name);
+        quote::quote! {
+            if !self.#is_set_name() {
+                missing.push(#field);
+            }
+        }
+    });
+    let mut accessor_generics = builder_generics.clone();
+    for (name, info) in &tracked {
+        let param = state_param(name);
+        let field_type = &info.type_;
+        accessor_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote! { #param: #maybe_set_trait<#field_type> });
+    }
+    let accessor_where_clause = &accessor_generics.where_clause;
+
+    let is_set_traits: Vec<_> = mandatory
+        .iter()
+        .map(|(name, _)| format_ident!("{}_{}_is_set", builder_name, name))
+        .collect();
+
+    let mut trait_defs: Vec<_> = mandatory.iter().zip(&is_set_traits).map(|((name, _), trait_name)| {
+        let message = format!(
+            "`{}::{}()` requires field `{}` to be set",
+            builder_name, build_fn, name
+        );
+        let label = format!("call `.{}(..)` before `.{}()`", name, build_fn);
+        quote::quote! {
+            #[allow(non_camel_case_types)]
+            #[diagnostic::on_unimplemented(message = #message, label = #label)]
+            pub trait #trait_name<T> {
+                fn value(self) -> T;
+            }
+
+            impl<T> #trait_name<T> for (T,) {
+                fn value(self) -> T {
+                    self.0
+                }
+            }
+        }
+    }).collect();
+
+    let mut build_bounds: Vec<_> = mandatory
+        .iter()
+        .zip(&is_set_traits)
+        .map(|((name, info), trait_name)| {
+            let param = state_param(name);
+            let field_type = &info.type_;
+            quote::quote! { #param: #trait_name<#field_type> }
+        })
+        .collect();
+
+    // значение отслеживаемого поля в любом состоянии: () - не задано, (T,) - задано.
+    // Через него build() читает необязательные отслеживаемые поля, а x_ref() - все
+    let maybe_set: Vec<_> = tracked.iter().filter(|(_, info)| !info.is_mandatory).collect();
+    if !tracked.is_empty() {
+        trait_defs.push(quote::quote! {
+            #[allow(non_camel_case_types)]
+            pub trait #maybe_set_trait<T> {
+                fn value(self) -> ::core::option::Option<T>;
+                fn value_ref(&self) -> ::core::option::Option<&T>;
+            }
+
+            impl<T> #maybe_set_trait<T> for () {
+                fn value(self) -> ::core::option::Option<T> {
+                    ::core::option::Option::None
+                }
+                fn value_ref(&self) -> ::core::option::Option<&T> {
+                    ::core::option::Option::None
+                }
+            }
+
+            impl<T> #maybe_set_trait<T> for (T,) {
+                fn value(self) -> ::core::option::Option<T> {
+                    ::core::option::Option::Some(self.0)
+                }
+                fn value_ref(&self) -> ::core::option::Option<&T> {
+                    ::core::option::Option::Some(&self.0)
+                }
+            }
+        });
+    }
+    for (name, info) in &maybe_set {
+        let param = state_param(name);
+        let field_type = &info.type_;
+        build_bounds.push(quote::quote! { #param: #maybe_set_trait<#field_type> });
+    }
+
+    // группы: трейт реализован только для допустимых сочетаний состояний
+    for group in groups(fields) {
+        let kind = match group.kind {
+            GroupKind::ExactlyOne => "exactly_one",
+            GroupKind::AtMostOne => "at_most_one",
+        };
+        let group_ident = group.name.replace(|c: char| !c.is_alphanumeric(), "_");
+        let trait_name = format_ident!("{}_{}_{}", builder_name, group_ident, kind);
+        let setters = group
+            .members
+            .iter()
+            .map(|info| format!("`.{}(..)`", info.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let (message, label) = match group.kind {
+            GroupKind::ExactlyOne => (
+                format!(
+                    "`{}::{}()` requires exactly one field of group `{}` to be set",
+                    builder_name, build_fn, group.name
+                ),
+                format!("call exactly one of {}", setters),
+            ),
+            GroupKind::AtMostOne => (
+                format!(
+                    "`{}::{}()` allows at most one field of group `{}` to be set",
+                    builder_name, build_fn, group.name
+                ),
+                format!("call at most one of {}", setters),
+            ),
+        };
+
+        let count = group.members.len();
+        let mut allowed: Vec<Option<usize>> = (0..count).map(Some).collect();
+        if group.kind == GroupKind::AtMostOne {
+            allowed.push(None);
+        }
+        let impls = allowed.into_iter().map(|set| {
+            let generic = set.map(|_| quote::quote! { <__T> });
+            let states = (0..count).map(|i| match set == Some(i) {
+                true => quote::quote! { (__T,) },
+                false => quote::quote! { () },
+            });
+            quote::quote! {
+                impl #generic #trait_name for (#(#states,)*) {}
+            }
+        });
+        trait_defs.push(quote::quote! {
+            #[allow(non_camel_case_types)]
+            #[diagnostic::on_unimplemented(message = #message, label = #label)]
+            pub trait #trait_name {}
+
+            #(#impls)*
+        });
+
+        let params = group.members.iter().map(|info| state_param(&info.name));
+        build_bounds.push(quote::quote! { (#(#params,)*): #trait_name });
+    }
+
+    // requires: если поле задано, задано и требуемое
+    for info in fields {
+        for required in &info.requires {
+            let trait_name = format_ident!("{}_{}_requires_{}", builder_name, info.name, required);
+            let message = format!(
+                "`{}::{}()` requires field `{}` to be set together with `{}`",
+                builder_name, build_fn, required, info.name
+            );
+            let label = format!("call `.{}(..)` before `.{}()`", required, build_fn);
+            trait_defs.push(quote::quote! {
+                #[allow(non_camel_case_types)]
+                #[diagnostic::on_unimplemented(message = #message, label = #label)]
+                pub trait #trait_name {}
+
+                impl<__B> #trait_name for ((), __B) {}
+                impl<__A, __B> #trait_name for ((__A,), (__B,)) {}
+            });
+
+            let field_param = state_param(&info.name);
+            let required_param = state_param(required);
+            build_bounds.push(quote::quote! { (#field_param, #required_param): #trait_name });
+        }
+    }
+
+    // итоговые значения полей, вычисляемые - после остальных
+    let build_ordered = build_order(fields);
+    let field_names: Vec<_> = build_ordered.iter().map(|info| &info.name).collect();
+    let field_values = build_ordered.iter().map(|info| {
+        let name = &info.name;
+        if let Some(compute) = &info.compute {
+            return quote::quote! { #compute };
+        }
+        match mandatory.iter().position(|(other, _)| *other == name) {
+            Some(i) => {
+                let trait_name = &is_set_traits[i];
+                quote::quote! { #trait_name::value(self.#name) }
+            }
+            None if tracked_names.contains(&name) => {
+                gen_optional_value(info, quote::quote! { #maybe_set_trait::value(self.#name) })
+            }
+            None => gen_optional_value(info, quote::quote! { self.#name }),
+        }
+    });
+    let struct_default = gen_struct_default(structure_name, generics, struct_attrs);
+
+    let build_fn = if struct_attrs.fallible || has_validation(struct_attrs, fields) {
+        let field_validation = gen_field_validation(error_name, fields);
+        let struct_validation = gen_struct_validation(error_name, struct_attrs);
+        quote::quote! {
+            #vis fn #build_fn(self) -> ::core::result::Result<#structure_name #ty_generics, #error_name>
+            where
+                #(#build_bounds),*
+            {
+                #struct_default
+                #(let #field_names = #field_values;)*
+                #field_validation
+
+                let __built = #structure_name {
+                    #(#field_names),*
+                };
+                #struct_validation
+                ::core::result::Result::Ok(__built)
+            }
+        }
+    } else {
+        quote::quote! {
+            #vis fn #build_fn(self) -> #structure_name #ty_generics
+            where
+                #(#build_bounds),*
+            {
+                #struct_default
+                #(let #field_names = #field_values;)*
+
+                #structure_name {
+                    #(#field_names),*
+                }
+            }
+        }
+    };
+
+    // из готовой структуры получается билдер со всеми заданными обязательными
+    // полями. Для необязательных отслеживаемых полей тип билдера зависел бы от
+    // значения, поэтому с ними обратного пути нет
+    let mandatory_names: Vec<_> = mandatory.iter().map(|(name, _)| *name).collect();
+    let mandatory_types: Vec<_> = mandatory.iter().map(|(_, info)| &info.type_).collect();
+    let filled_type = quote::quote! {
+        #builder_name<#(#input_args,)* #((#mandatory_types,)),*>
+    };
+    let filled_builder = |source: fn(&Ident) -> TokenStream2| {
+        let mandatory_values = mandatory_names.iter().map(|name| source(name));
+        let optional_values = optional.iter().map(|(name, info)| {
+            let value = source(name);
+            match info.is_option {
+                true => quote::quote! { #value },
+                false => quote::quote! { ::core::option::Option::Some(#value) },
+            }
+        });
+        quote::quote! {
+            #builder_name {
+                #(#mandatory_names: (#mandatory_values,),)*
+                #(#optional_names: #optional_values,)*
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+    };
+    // таблица полей - у начального билдера, чтобы путь к ней не требовал состояний
+    let fields_table = gen_fields_table(builder_name, vis, fields);
+
+    let round_trip_code = if maybe_set.is_empty() {
+        let from_value = filled_builder(|name| quote::quote! { value.#name });
+        let to_builder = gen_to_builder(
+            structure_name,
+            generics,
+            fields,
+            filled_type.clone(),
+            filled_builder(|name| quote::quote! { ::core::clone::Clone::clone(&self.#name) }),
+        );
+        quote::quote! {
+            impl #impl_generics ::core::convert::From<#structure_name #ty_generics> for #filled_type #where_clause {
+                fn from(value: #structure_name #ty_generics) -> Self {
+                    #from_value
+                }
+            }
+
+            #to_builder
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    quote::quote! {
+        #[allow(non_camel_case_types)]
+        #[derive(#derives)]
+        #vis struct #builder_name #decl_generics #where_clause {
+            #(#tracked_names: #state_params,)*
+            #(#optional_names: ::core::option::Option<#optional_types>,)*
+            // параметры структуры могут встречаться только в отслеживаемых полях
+            __marker: ::core::marker::PhantomData<fn() -> #structure_name #ty_generics>,
+        }
+
+        impl #impl_generics #structure_name #ty_generics #where_clause {
+            pub fn builder() -> #builder_name #ty_generics {
+                #builder_name {
+                    #(#tracked_names: (),)*
+                    #(#optional_names: ::core::option::Option::None,)*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        #[allow(non_camel_case_types)]
+        impl #builder_impl_generics #builder_name #builder_ty_generics #where_clause {
+            #(#tracked_setters)*
+
+            #(#optional_setters)*
+
+            #(#optional_accessors)*
+        }
+
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            #fields_table
+        }
+
+        #round_trip_code
+
+        const _: () = {
+            #(#trait_defs)*
+
+            #[allow(non_camel_case_types)]
+            impl #builder_impl_generics #builder_name #builder_ty_generics #accessor_where_clause {
+                #(#tracked_accessors)*
+
+                #vis fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+                    let mut missing = ::std::vec::Vec::new();
+                    #(#missing_checks)*
+                    missing
+                }
+            }
+
+            #[allow(non_camel_case_types)]
+            impl #builder_impl_generics #builder_name #builder_ty_generics #where_clause {
+                #build_fn
+            }
+        };
+    }
+}
@@ -1,43 +1,65 @@
-pub fn extract_type_from_option(ty: &syn::Type) -> Option<&syn::Type> {
-    use syn::{GenericArgument, Path, PathArguments, PathSegment};
+use field_type::FieldType;
 
-    fn extract_type_path(ty: &syn::Type) -> Option<&Path> {
-        match *ty {
-            syn::Type::Path(ref typepath) if typepath.qself.is_none() => Some(&typepath.path),
-            _ => None,
+// Элемент коллекции, которую можно пополнять через Extend
+pub enum CollectionItem {
+    // Vec<T>, VecDeque<T>, HashSet<T>, BTreeSet<T> и любые другие коллекции с Extend
+    Single(Box<syn::Type>),
+    // HashMap<K, V>, BTreeMap<K, V> - пополняются парами (K, V)
+    Pair(Box<syn::Type>, Box<syn::Type>),
+}
+
+// Элемент коллекции для each. Словари узнаются через field_type и пополняются
+// парами; у остальных элемент - <T as IntoIterator>::Item, а Extend проверит компилятор
+pub fn extract_collection_item(ty: &syn::Type) -> CollectionItem {
+    match field_type::classify(ty) {
+        FieldType::Vec(item) | FieldType::VecDeque(item) | FieldType::Set(item) => {
+            CollectionItem::Single(Box::new(item.clone()))
         }
+        FieldType::Map(key, value) => CollectionItem::Pair(Box::new(key.clone()), Box::new(value.clone())),
+        _ => CollectionItem::Single(Box::new(syn::parse_quote! { <#ty as ::core::iter::IntoIterator>::Item })),
     }
+}
 
-    // TODO store (with lazy static) the vec of string
-    // TODO maybe optimization, reverse the order of segments
-    fn extract_option_segment(path: &Path) -> Option<&PathSegment> {
-        let idents_of_path = path
-            .segments
-            .iter()
-            .into_iter()
-            .fold(String::new(), |mut acc, v| {
-                acc.push_str(&v.ident.to_string());
-                acc.push('|');
-                acc
-            });
-        vec!["Option|", "std|option|Option|", "core|option|Option|"]
-            .into_iter()
-            .find(|s| &idents_of_path == *s)
-            .and_then(|_| path.segments.last())
-    }
+// Имя поля для пользователя - в таблицах, ошибках и set_by_name: r#type -> "type"
+pub fn field_name(name: &syn::Ident) -> String {
+    syn::ext::IdentExt::unraw(name).to_string()
+}
 
-    extract_type_path(ty)
-        .and_then(|path| extract_option_segment(path))
-        .and_then(|path_seg| {
-            let type_params = &path_seg.arguments;
-            // It should have only on angle-bracketed param ("<String>"):
-            match *type_params {
-                PathArguments::AngleBracketed(ref params) => params.args.first(),
-                _ => None,
+// spawn_process -> SpawnProcess
+pub fn to_upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
             }
         })
-        .and_then(|generic_arg| match *generic_arg {
-            GenericArgument::Type(ref ty) => Some(ty),
+        .collect()
+}
+
+// Текст doc-комментария: строки /// без ведущих пробелов, через \n
+pub fn doc_comment(attrs: &[syn::Attribute]) -> String {
+    use syn::{Expr, ExprLit, Lit, Meta};
+
+    attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(ExprLit { lit: Lit::Str(doc), .. }) => Some(doc.value().trim().to_owned()),
+                _ => None,
+            },
             _ => None,
         })
-}
\ No newline at end of file
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Тип так, как его пишут руками: Option<Vec<u8>>, а не Option < Vec < u8 > >
+pub fn type_string(ty: &syn::Type) -> String {
+    let tokens = quote::quote! { #ty }.to_string();
+    [(" :: ", "::"), (":: ", "::"), (" <", "<"), ("< ", "<"), (" >", ">"), (" ,", ","), ("& ", "&"), (" ;", ";")]
+        .iter()
+        .fold(tokens, |acc, (from, to)| acc.replace(from, to))
+}
@@ -0,0 +1,44 @@
+// With #[builder(typestate)] every mandatory field becomes a type parameter of
+// the builder. Calling a setter for a mandatory field changes the type of the
+// builder, and `build` only becomes callable once every mandatory field has
+// been set. Since nothing can be missing at that point, `build` returns the
+// struct directly instead of a Result.
+//
+//     struct CommandBuilder<__executable = (), __args = (), ...> {
+//         executable: __executable,
+//         args: __args,
+//         current_dir: Option<String>,
+//     }
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    env: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .args(vec!["build".to_owned(), "--release".to_owned()])
+        .executable("cargo".to_owned())
+        .env(vec![])
+        .build();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert!(command.env.is_empty());
+    assert!(command.current_dir.is_none());
+
+    let command = Command::builder()
+        .current_dir("..".to_owned())
+        .executable("rustc".to_owned())
+        .executable("cargo".to_owned())
+        .args(vec![])
+        .env(vec![])
+        .build();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+}
@@ -0,0 +1,18 @@
+// In typestate mode forgetting a mandatory field is a compile error, and the
+// error names the field that was not set.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let _command = Command::builder()
+        .args(vec!["build".to_owned()])
+        .build();
+}
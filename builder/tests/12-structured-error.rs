@@ -0,0 +1,49 @@
+// The error returned by `build` is an enum generated for each struct,
+// `{Name}BuilderError`, so callers can match on it. All mandatory fields that
+// were not set are reported at once, in declaration order.
+//
+//     #[non_exhaustive]
+//     enum CommandBuilderError {
+//         MissingFields(Vec<&'static str>),
+//         InvalidField { field: &'static str, message: String },
+//         UnknownField(String),
+//     }
+//
+// The enum only has the variants this builder can return: `MissingFields`
+// because of the mandatory fields, and `InvalidField` and `UnknownField` for
+// `set_by_name`. Options such as `validate` or `group` add their own variants,
+// so the enum is `#[non_exhaustive]`.
+//
+// The error implements std::error::Error and Display, so it still converts into
+// Box<dyn Error> with the `?` operator.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    env: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn build_boxed() -> Result<Command, Box<dyn std::error::Error>> {
+    let command = Command::builder().env(vec![]).build()?;
+    Ok(command)
+}
+
+fn main() {
+    let err = Command::builder().env(vec![]).build().err().unwrap();
+    assert_eq!(err, CommandBuilderError::MissingFields(vec!["executable", "args"]));
+    assert_eq!(err.to_string(), "missing required fields: executable, args");
+
+    match Command::builder().executable("cargo".to_owned()).build() {
+        Err(CommandBuilderError::MissingFields(fields)) => {
+            assert_eq!(fields, vec!["args", "env"]);
+        }
+        _ => panic!("expected missing fields"),
+    }
+
+    let err = build_boxed().err().unwrap();
+    assert_eq!(err.to_string(), "missing required fields: executable, args");
+}
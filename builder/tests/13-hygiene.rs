@@ -0,0 +1,49 @@
+// The expansion must not inject `use` items or shared helper types into the
+// caller's module. Everything is referred to through absolute paths, so any
+// number of derives can live next to each other, next to the caller's own
+// `Error`, `Result` or `Display`.
+
+use derive_builder::Builder;
+
+#[allow(unused_imports)]
+use std::io::Error;
+
+#[allow(dead_code)]
+type Result<T> = std::result::Result<T, Error>;
+
+#[allow(dead_code)]
+trait Display {}
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder)]
+pub struct Process {
+    pid: u32,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Job {
+    name: String,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Task {
+    name: String,
+}
+
+fn main() {
+    let command = Command::builder().executable("cargo".to_owned()).build().unwrap();
+    assert_eq!(command.executable, "cargo");
+
+    let err = Process::builder().build().err().unwrap();
+    assert_eq!(err, ProcessBuilderError::MissingFields(vec!["pid"]));
+
+    assert_eq!(Job::builder().name("job".to_owned()).build().name, "job");
+    assert_eq!(Task::builder().name("task".to_owned()).build().name, "task");
+}
@@ -0,0 +1,97 @@
+// `each` works for any collection that implements Extend. The item type is
+// taken from the standard collections' type arguments, and from
+// `<T as IntoIterator>::Item` for everything else. For HashMap and BTreeMap the
+// one-at-a-time setter takes a key and a value. Every `each` field also gets an `extend_<field>` setter that
+// accepts anything iterable, and fields using `each` start out empty instead of
+// being reported as missing.
+
+use derive_builder::Builder;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+// a user-defined collection: only Default, Extend and IntoIterator matter
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Bag(Vec<u8>);
+
+impl Extend<u8> for Bag {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl IntoIterator for Bag {
+    type Item = u8;
+    type IntoIter = std::vec::IntoIter<u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: HashMap<String, String>,
+    #[builder(each = "label")]
+    labels: BTreeMap<String, u32>,
+    #[builder(each = "feature")]
+    features: HashSet<String>,
+    #[builder(each = "stage")]
+    stages: VecDeque<u8>,
+    #[builder(each = "weight")]
+    weights: Bag,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Job {
+    name: String,
+    #[builder(each = "step")]
+    steps: Vec<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .extend_args(vec!["--release".to_owned(), "--locked".to_owned()])
+        .env("RUST_LOG".to_owned(), "debug".to_owned())
+        .extend_env(vec![("HOME".to_owned(), "/root".to_owned())])
+        .label("priority".to_owned(), 1)
+        .feature("serde".to_owned())
+        .feature("serde".to_owned())
+        .stage(2)
+        .stage(1)
+        .weight(3)
+        .extend_weights(vec![4, 5])
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["build", "--release", "--locked"]);
+    assert_eq!(command.env["RUST_LOG"], "debug");
+    assert_eq!(command.env["HOME"], "/root");
+    assert_eq!(command.labels["priority"], 1);
+    assert_eq!(command.features.len(), 1);
+    assert_eq!(command.stages, VecDeque::from(vec![2, 1]));
+    assert_eq!(command.weights, Bag(vec![3, 4, 5]));
+
+    // the all-at-once setter is still there when the names differ
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .args(vec!["test".to_owned()])
+        .arg("--workspace".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.args, vec!["test", "--workspace"]);
+    assert!(command.env.is_empty());
+    assert!(command.labels.is_empty());
+    assert!(command.features.is_empty());
+    assert!(command.stages.is_empty());
+    assert_eq!(command.weights, Bag::default());
+
+    let job = Job::builder().step("fetch".to_owned()).name("ci".to_owned()).build();
+    assert_eq!(job.steps, vec!["fetch"]);
+    assert!(Job::builder().name("ci".to_owned()).build().steps.is_empty());
+}
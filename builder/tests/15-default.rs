@@ -0,0 +1,88 @@
+// #[builder(default)] fills a field with Default::default() when its setter was
+// not called, and #[builder(default = "...")] takes an arbitrary expression. The
+// expression is only evaluated inside `build` when it is needed, and may refer
+// to fields declared above it.
+//
+// #[builder(default)] on the struct itself takes every field that was left
+// unset from the struct's own Default impl.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(default)]
+    args: Vec<String>,
+    #[builder(default = "30")]
+    timeout_secs: u64,
+    #[builder(default = "timeout_secs / 10")]
+    retries: u64,
+    #[builder(default = "format!(\"/usr/bin/{}\", executable)")]
+    path: String,
+    #[builder(default = "Some(\"..\".to_owned())")]
+    current_dir: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(default)]
+pub struct Limits {
+    cpus: u32,
+    memory_mb: u32,
+    #[builder(default = "cpus * 2")]
+    threads: u32,
+    label: Option<String>,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            cpus: 1,
+            memory_mb: 512,
+            threads: 0,
+            label: Some("default".to_owned()),
+        }
+    }
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Job {
+    name: String,
+    #[builder(default = "name.len()")]
+    weight: usize,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .build()
+        .unwrap();
+    assert!(command.args.is_empty());
+    assert_eq!(command.timeout_secs, 30);
+    assert_eq!(command.retries, 3);
+    assert_eq!(command.path, "/usr/bin/cargo");
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let command = Command::builder()
+        .executable("rustc".to_owned())
+        .timeout_secs(100)
+        .path("/opt/rustc".to_owned())
+        .current_dir("/tmp".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.retries, 10);
+    assert_eq!(command.path, "/opt/rustc");
+    assert_eq!(command.current_dir.as_deref(), Some("/tmp"));
+
+    let err = Command::builder().build().err().unwrap();
+    assert_eq!(err, CommandBuilderError::MissingFields(vec!["executable"]));
+
+    let limits = Limits::builder().cpus(4).build();
+    assert_eq!(limits.cpus, 4);
+    assert_eq!(limits.memory_mb, 512);
+    assert_eq!(limits.threads, 8);
+    assert_eq!(limits.label.as_deref(), Some("default"));
+
+    let job = Job::builder().name("build".to_owned()).build();
+    assert_eq!(job.weight, 5);
+}
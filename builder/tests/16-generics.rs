@@ -0,0 +1,73 @@
+// The builder carries the generic parameters, lifetimes and where-clause of the
+// input struct. Setters take the field types as written, so setters for fields
+// that borrow with the struct's lifetime work as well.
+//
+//     struct RequestBuilder<'a, B: Body> where B: Clone {
+//         method: Option<Cow<'a, str>>,
+//         body: Option<B>,
+//         ...
+//     }
+
+use derive_builder::Builder;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+pub trait Body {
+    fn size(&self) -> usize;
+}
+
+impl Body for String {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+#[derive(Builder)]
+pub struct Request<'a, B: Body, const N: usize>
+where
+    B: Clone,
+{
+    method: Cow<'a, str>,
+    body: B,
+    #[builder(each = "header")]
+    headers: Vec<&'a str>,
+    referer: Option<&'a str>,
+    #[builder(default)]
+    marker: PhantomData<[u8; N]>,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Response<'a, B: Body> {
+    status: u16,
+    body: B,
+    reason: Option<&'a str>,
+}
+
+fn main() {
+    let host = String::from("Host: localhost");
+    let request = Request::<String, 4>::builder()
+        .method(Cow::Borrowed("GET"))
+        .body("hello".to_owned())
+        .header(&host)
+        .header("Accept: */*")
+        .build()
+        .unwrap();
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.body.size(), 5);
+    assert_eq!(request.headers, vec!["Host: localhost", "Accept: */*"]);
+    assert!(request.referer.is_none());
+
+    let err = Request::<String, 4>::builder().build().err().unwrap();
+    assert_eq!(err, RequestBuilderError::MissingFields(vec!["method", "body"]));
+
+    let reason = String::from("OK");
+    let response = Response::builder()
+        .reason(&reason)
+        .body("world".to_owned())
+        .status(200)
+        .build();
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body.size(), 5);
+    assert_eq!(response.reason, Some("OK"));
+}
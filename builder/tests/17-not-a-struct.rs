@@ -0,0 +1,18 @@
+// Builder can only be derived for structs with named fields. Anything else is
+// reported as a compile error pointing at the offending input rather than a
+// panic inside the macro.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub enum Command {
+    Run { executable: String },
+}
+
+#[derive(Builder)]
+pub struct Pair(String, String);
+
+#[derive(Builder)]
+pub struct Unit;
+
+fn main() {}
@@ -0,0 +1,43 @@
+// Fields whose type is not a plain path are ordinary mandatory fields:
+// references, arrays, tuples, function pointers and qualified paths.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Packet<'a> {
+    name: &'a str,
+    addr: [u8; 4],
+    port_range: (u16, u16),
+    on_send: fn(usize) -> usize,
+    first: <Vec<u8> as IntoIterator>::Item,
+    payload: Option<&'a [u8]>,
+}
+
+fn double(n: usize) -> usize {
+    n * 2
+}
+
+fn main() {
+    let packet = Packet::builder()
+        .name("ping")
+        .addr([127, 0, 0, 1])
+        .port_range((8000, 8080))
+        .on_send(double)
+        .first(1)
+        .payload(b"data")
+        .build()
+        .unwrap();
+
+    assert_eq!(packet.name, "ping");
+    assert_eq!(packet.addr, [127, 0, 0, 1]);
+    assert_eq!(packet.port_range.1, 8080);
+    assert_eq!((packet.on_send)(21), 42);
+    assert_eq!(packet.first, 1);
+    assert_eq!(packet.payload, Some(&b"data"[..]));
+
+    let err = Packet::builder().name("ping").build().err().unwrap();
+    assert_eq!(
+        err,
+        PacketBuilderError::MissingFields(vec!["addr", "port_range", "on_send", "first"]),
+    );
+}
@@ -0,0 +1,68 @@
+// #[builder(pattern = "...")] selects how setters and `build` take the builder.
+//
+//   - "mutable" (the default): setters take and return `&mut Self`, `build`
+//     clones every field out of the builder.
+//
+//   - "owned": setters take and return `self`, and `build(self)` moves the
+//     fields out of the builder without cloning them.
+//
+//   - "immutable": setters take `&self` and return a modified copy, so one
+//     partially filled builder can serve as a template for several others.
+
+use derive_builder::Builder;
+
+// deliberately not Clone: the owned pattern must never clone fields
+#[derive(Debug)]
+pub struct Handle(u32);
+
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    handle: Handle,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "immutable")]
+pub struct Job {
+    name: String,
+    #[builder(each = "step")]
+    steps: Vec<String>,
+    retries: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "mutable")]
+pub struct Task {
+    name: String,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .handle(Handle(7))
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.handle.0, 7);
+
+    let template = Job::builder().step("fetch".to_owned()).retries(3);
+    let build = template.name("build".to_owned()).step("compile".to_owned());
+    let test = template.name("test".to_owned());
+
+    let build = build.build().unwrap();
+    let test = test.build().unwrap();
+    assert_eq!(build.steps, vec!["fetch", "compile"]);
+    assert_eq!(test.steps, vec!["fetch"]);
+    assert_eq!(test.name, "test");
+    assert_eq!(test.retries, Some(3));
+    assert!(template.build().is_err());
+
+    let mut builder = Task::builder();
+    builder.name("lint".to_owned());
+    assert_eq!(builder.build().unwrap().name, "lint");
+}
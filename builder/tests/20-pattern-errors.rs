@@ -0,0 +1,18 @@
+// Unknown builder patterns are rejected, and a typestate builder cannot use
+// anything but the owned pattern since each setter changes the builder's type.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(pattern = "shared")]
+pub struct Command {
+    executable: String,
+}
+
+#[derive(Builder)]
+#[builder(typestate, pattern = "mutable")]
+pub struct Job {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,84 @@
+// #[builder(setter(into))] makes a setter accept anything that converts into
+// the field type, so callers can write `.executable("cargo")`. For `each`
+// fields it applies to the one-at-a-time setter too.
+//
+// #[builder(setter(strip_option = false))] makes the setter of an Option<T>
+// field take Option<T> itself, so a caller can pass `None` explicitly. Passing
+// `None` leaves the field unset. Combined with `into`, such a setter accepts
+// either a `T` or an `Option<T>`.
+//
+// Both can be given on the struct as a default for every field and overridden
+// per field.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(setter(into))]
+    executable: String,
+    #[builder(each = "arg", setter(into))]
+    args: Vec<String>,
+    #[builder(each = "env", setter(into))]
+    env: HashMap<String, String>,
+    #[builder(setter(strip_option = false))]
+    current_dir: Option<String>,
+    #[builder(setter(into, strip_option = false))]
+    user: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(setter(into, strip_option = false))]
+pub struct Job {
+    name: String,
+    timeout: Option<u64>,
+    #[builder(setter(into = false, strip_option))]
+    owner: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(typestate, setter(into))]
+pub struct Task {
+    name: String,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo")
+        .arg("build")
+        .arg(String::from("--release"))
+        .env("RUST_LOG", "debug")
+        .current_dir(None)
+        .user("root".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.env["RUST_LOG"], "debug");
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.user.as_deref(), Some("root"));
+
+    let command = Command::builder()
+        .executable("cargo")
+        .current_dir(Some("..".to_owned()))
+        .user(None)
+        .build()
+        .unwrap();
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+    assert_eq!(command.user, None);
+
+    let job = Job::builder()
+        .name("nightly")
+        .timeout(60)
+        .owner("ci".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(job.name, "nightly");
+    assert_eq!(job.timeout, Some(60));
+    assert_eq!(job.owner.as_deref(), Some("ci"));
+
+    let job = Job::builder().name("nightly").timeout(None).build().unwrap();
+    assert_eq!(job.timeout, None);
+
+    assert_eq!(Task::builder().name("lint").build().name, "lint");
+}
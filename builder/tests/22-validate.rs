@@ -0,0 +1,95 @@
+// Invariants are checked by `build()` itself. A field-level
+// `#[builder(validate = "path")]` receives a reference to the final value of
+// the field (after defaults are applied), a struct-level one receives the
+// assembled struct. Both return `Result<(), E>` with `E: Display`, and a
+// failure is reported through the builder error:
+//
+//     CommandBuilderError::InvalidField { field: "port", message }
+//     CommandBuilderError::Invalid(message)
+//
+// Field validators run in declaration order, before the struct validator.
+// In typestate mode `build()` becomes fallible once any validator is present.
+
+use derive_builder::Builder;
+
+mod checks {
+    pub fn non_zero(port: &u16) -> Result<(), &'static str> {
+        match *port {
+            0 => Err("must not be zero"),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn shell_args(command: &super::Command) -> Result<(), String> {
+        if command.executable.ends_with("sh") && command.args.is_empty() {
+            return Err(format!("`{}` needs arguments", command.executable));
+        }
+        Ok(())
+    }
+}
+
+fn not_empty(value: &str) -> Result<(), String> {
+    match value.is_empty() {
+        true => Err("must not be empty".to_owned()),
+        false => Ok(()),
+    }
+}
+
+#[derive(Builder)]
+#[builder(validate = "checks::shell_args")]
+pub struct Command {
+    #[builder(validate = "not_empty")]
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(default = "8080", validate = "checks::non_zero")]
+    port: u16,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Server {
+    #[builder(validate = "not_empty")]
+    host: String,
+    #[builder(validate = "checks::non_zero")]
+    port: u16,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("bash".to_owned())
+        .arg("-c".to_owned())
+        .build()
+        .ok()
+        .unwrap();
+    assert_eq!(command.port, 8080);
+
+    let err = Command::builder()
+        .executable("cargo".to_owned())
+        .port(0)
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        CommandBuilderError::InvalidField {
+            field: "port",
+            message: "must not be zero".to_owned(),
+        }
+    );
+    assert_eq!(err.to_string(), "invalid value for field `port`: must not be zero");
+
+    // field validators run before the struct validator
+    let err = Command::builder().executable(String::new()).port(0).build().err().unwrap();
+    assert!(matches!(err, CommandBuilderError::InvalidField { field: "executable", .. }));
+
+    let err = Command::builder().executable("zsh".to_owned()).build().err().unwrap();
+    assert_eq!(err, CommandBuilderError::Invalid("`zsh` needs arguments".to_owned()));
+    assert_eq!(err.to_string(), "validation failed: `zsh` needs arguments");
+
+    let server = Server::builder().host("localhost".to_owned()).port(80).build().ok().unwrap();
+    assert_eq!(server.port, 80);
+
+    let err = Server::builder().host("localhost".to_owned()).port(0).build().err().unwrap();
+    assert_eq!(err.to_string(), "invalid value for field `port`: must not be zero");
+}
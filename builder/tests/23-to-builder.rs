@@ -0,0 +1,78 @@
+// An existing value can be turned back into a builder with every field
+// pre-filled, changed and rebuilt:
+//
+//     impl From<Command> for CommandBuilder { ... }
+//
+//     impl Command {
+//         pub fn to_builder(&self) -> CommandBuilder { ... }
+//     }
+//
+// `From` moves the fields, `to_builder` clones them and is only callable when
+// every field type is Clone. An Option field holding None stays unset. A
+// typestate builder created this way has all of its mandatory fields set, so
+// it can be built right away.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+// Not Clone: with an owned builder `From` works, `to_builder` cannot be called.
+#[derive(Debug)]
+pub struct Handle(u32);
+
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+pub struct Process<T> {
+    command: T,
+    handle: Handle,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Server {
+    host: String,
+    #[builder(default = "80")]
+    port: u16,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .build()
+        .ok()
+        .unwrap();
+
+    let release = command
+        .to_builder()
+        .arg("--release".to_owned())
+        .current_dir("..".to_owned())
+        .build()
+        .ok()
+        .unwrap();
+    assert_eq!(release.executable, "cargo");
+    assert_eq!(release.args, vec!["build", "--release"]);
+    assert_eq!(release.current_dir, Some("..".to_owned()));
+    assert_eq!(command.args, vec!["build"]);
+
+    let mut builder = CommandBuilder::from(release);
+    let rebuilt = builder.executable("rustc".to_owned()).build().ok().unwrap();
+    assert_eq!(rebuilt.executable, "rustc");
+    assert_eq!(rebuilt.args, vec!["build", "--release"]);
+
+    let process = Process::builder().command(command).handle(Handle(7)).build().ok().unwrap();
+    let process = ProcessBuilder::from(process).handle(Handle(8)).build().ok().unwrap();
+    assert_eq!(process.handle.0, 8);
+    assert_eq!(process.command.executable, "cargo");
+
+    let server = Server::builder().host("localhost".to_owned()).build();
+    let server = server.to_builder().port(8080).build();
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.port, 8080);
+}
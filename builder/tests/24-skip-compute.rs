@@ -0,0 +1,51 @@
+// Fields that are derived from other fields never get a setter.
+//
+// #[builder(skip)] leaves the field out of the builder and initializes it with
+// its default: `default = "expr"` if given, the struct's Default under
+// #[builder(default)], and `Default::default()` otherwise.
+//
+// #[builder(compute = "expr")] evaluates the expression inside `build()`.
+// It runs after every other field has been resolved, defaults included, and
+// can refer to them by name. Computed fields are evaluated in declaration
+// order, so a later one can use an earlier one.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(compute = "format!(\"{}/{}\", current_dir.as_deref().unwrap_or(\".\"), executable)")]
+    full_path: String,
+    executable: String,
+    current_dir: Option<String>,
+    #[builder(skip)]
+    runs: u32,
+    #[builder(skip, default = "vec![\"-q\".to_owned()]")]
+    flags: Vec<String>,
+    #[builder(compute = "full_path.len()")]
+    path_len: usize,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Server {
+    host: String,
+    #[builder(default = "80")]
+    port: u16,
+    #[builder(compute = "format!(\"{}:{}\", host, port)")]
+    address: String,
+}
+
+fn main() {
+    let command = Command::builder().executable("cargo".to_owned()).build().ok().unwrap();
+    assert_eq!(command.full_path, "./cargo");
+    assert_eq!(command.runs, 0);
+    assert_eq!(command.flags, vec!["-q"]);
+    assert_eq!(command.path_len, 7);
+
+    // computed fields are recomputed when the value is rebuilt
+    let command = command.to_builder().current_dir("/usr/bin".to_owned()).build().ok().unwrap();
+    assert_eq!(command.full_path, "/usr/bin/cargo");
+
+    let server = Server::builder().host("localhost".to_owned()).build();
+    assert_eq!(server.address, "localhost:80");
+}
@@ -0,0 +1,26 @@
+// A field without a setter cannot also be configured as if it had one, and
+// `skip` and `compute` are two different ways to fill the same field.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(skip, compute = "executable.len()")]
+    name_len: usize,
+}
+
+#[derive(Builder)]
+pub struct Job {
+    #[builder(skip, each = "arg")]
+    args: Vec<String>,
+}
+
+#[derive(Builder)]
+pub struct Task {
+    name: String,
+    #[builder(default = "0", compute = "name.len()")]
+    name_len: usize,
+}
+
+fn main() {}
@@ -0,0 +1,72 @@
+// The builder can be renamed and given its own visibility, build method name
+// and extra derives:
+//
+//     #[builder(name = "CommandConfig", vis = "pub(crate)", build_fn(name = "finish"), derive(Clone, PartialEq))]
+//
+// Without `vis` the builder, its methods and its error type inherit the
+// visibility of the target struct, so a `pub` struct can be built from outside
+// of its module. The error type is named after the builder.
+//
+// The builder always implements Default, so `derive(Default)` carried over
+// from older versions is accepted and adds nothing.
+
+mod process {
+    use derive_builder::Builder;
+
+    #[derive(Builder)]
+    pub struct Command {
+        pub executable: String,
+        pub args: Vec<String>,
+    }
+
+    #[derive(Builder)]
+    #[builder(
+        name = "JobConfig",
+        vis = "pub(crate)",
+        build_fn(name = "finish"),
+        derive(Clone, PartialEq, Default)
+    )]
+    pub struct Job {
+        pub name: String,
+        pub retries: Option<u32>,
+    }
+
+    #[derive(Builder)]
+    #[builder(typestate, build_fn(name = "finish"), derive(Clone))]
+    pub struct Server {
+        pub host: String,
+    }
+}
+
+use process::{Command, CommandBuilderError, Job, JobConfig, JobConfigError, Server};
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .args(vec![])
+        .build()
+        .ok()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+
+    let err = Command::builder().build().err().unwrap();
+    assert_eq!(err, CommandBuilderError::MissingFields(vec!["executable", "args"]));
+
+    let mut config: JobConfig = Job::builder();
+    config.name("nightly".to_owned());
+    let copy = config.clone();
+    assert_eq!(config, copy);
+
+    let job = config.retries(3).finish().ok().unwrap();
+    assert_eq!(job.name, "nightly");
+    assert_eq!(job.retries, Some(3));
+
+    assert_eq!(JobConfig::default(), Job::builder());
+
+    let err: JobConfigError = Job::builder().finish().err().unwrap();
+    assert_eq!(err.to_string(), "missing required fields: name");
+
+    let server = Server::builder().host("localhost".to_owned());
+    let server = server.clone().finish();
+    assert_eq!(server.host, "localhost");
+}
@@ -0,0 +1,131 @@
+// A field whose type derives Builder itself can be configured through that
+// builder with #[builder(sub_builder)]:
+//
+//     config.tls(|b| b.cert("cert.pem").key("key.pem"));
+//     config.tls_mut().cert("other.pem");
+//
+// The nested builder is `{Type}Builder` next to the field type, or the type
+// given as #[builder(sub_builder = "...")], and must use the default mutable
+// pattern: the closure receives and returns `&mut` to it. The parent's
+// `build()` builds it, and its errors are reported under the field path:
+//
+//     ServiceConfigBuilderError::Nested(vec![("tls.cert", "missing")])
+//
+// which displays as `tls.cert: missing`. Nested builders are built before the
+// parent checks its own fields, so missing fields on both levels are reported
+// together: `name: missing, tls.key: missing`.
+
+use derive_builder::Builder;
+
+mod tls {
+    use derive_builder::Builder;
+
+    #[derive(Builder)]
+    pub struct TlsConfig {
+        #[builder(setter(into))]
+        pub cert: String,
+        #[builder(setter(into))]
+        pub key: String,
+    }
+}
+
+#[derive(Builder)]
+#[builder(validate = "positive_attempts")]
+pub struct RetryPolicy {
+    #[builder(default = "3")]
+    attempts: u32,
+}
+
+fn positive_attempts(policy: &RetryPolicy) -> Result<(), &'static str> {
+    match policy.attempts {
+        0 => Err("at least one attempt is required"),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Builder)]
+pub struct ServiceConfig {
+    name: String,
+    #[builder(sub_builder)]
+    tls: tls::TlsConfig,
+    #[builder(sub_builder = "RetryPolicyBuilder")]
+    retry: RetryPolicy,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+pub struct Deployment {
+    #[builder(sub_builder)]
+    service: ServiceConfig,
+}
+
+fn main() {
+    let mut builder = ServiceConfig::builder();
+    builder
+        .name("api".to_owned())
+        .tls(|b| b.cert("cert.pem").key("key.pem"));
+    builder.tls_mut().cert("other.pem");
+    let config = builder.build().ok().unwrap();
+    assert_eq!(config.tls.cert, "other.pem");
+    assert_eq!(config.tls.key, "key.pem");
+    assert_eq!(config.retry.attempts, 3);
+
+    let err = ServiceConfig::builder()
+        .name("api".to_owned())
+        .tls(|b| b.key("key.pem"))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        ServiceConfigBuilderError::Nested(vec![("tls.cert".to_owned(), "missing".to_owned())])
+    );
+    assert_eq!(err.to_string(), "tls.cert: missing");
+
+    let err = ServiceConfig::builder()
+        .name("api".to_owned())
+        .tls(|b| b.cert("cert.pem").key("key.pem"))
+        .retry(|b| b.attempts(0))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "retry: at least one attempt is required");
+
+    // missing fields of the parent and of a nested builder in one error
+    let err = ServiceConfig::builder()
+        .tls(|b| b.cert("cert.pem"))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        ServiceConfigBuilderError::Nested(vec![
+            ("name".to_owned(), "missing".to_owned()),
+            ("tls.key".to_owned(), "missing".to_owned()),
+        ])
+    );
+    assert_eq!(err.to_string(), "name: missing, tls.key: missing");
+
+    // paths grow with every level of nesting
+    let err = Deployment::builder()
+        .service(|b| b.name("api".to_owned()))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "service.tls.cert: missing, service.tls.key: missing");
+
+    let deployment = Deployment::builder()
+        .service(|b| b.name("api".to_owned()).tls(|b| b.cert("cert.pem").key("key.pem")))
+        .build()
+        .ok()
+        .unwrap();
+    // converting back pre-fills the nested builder too
+    let rebuilt = DeploymentBuilder::from(deployment)
+        .service(|b| b.tls(|b| b.key("other.pem")))
+        .build()
+        .ok()
+        .unwrap();
+    assert_eq!(rebuilt.service.name, "api");
+    assert_eq!(rebuilt.service.tls.cert, "cert.pem");
+    assert_eq!(rebuilt.service.tls.key, "other.pem");
+}
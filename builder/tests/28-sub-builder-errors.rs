@@ -0,0 +1,31 @@
+// A nested builder replaces the field's own storage, so it cannot be combined
+// with options that describe that storage, and typestate builders do not
+// support it.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct TlsConfig {
+    cert: String,
+}
+
+#[derive(Builder)]
+pub struct Service {
+    #[builder(sub_builder)]
+    tls: Option<TlsConfig>,
+}
+
+#[derive(Builder)]
+pub struct Proxy {
+    #[builder(sub_builder)]
+    upstreams: (TlsConfig, TlsConfig),
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Server {
+    #[builder(sub_builder)]
+    tls: TlsConfig,
+}
+
+fn main() {}
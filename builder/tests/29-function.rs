@@ -0,0 +1,120 @@
+// The attribute form builds the arguments of a free function instead of a
+// struct:
+//
+//     #[builder]
+//     fn spawn(executable: String, args: Vec<String>, current_dir: Option<String>) -> Child
+//
+// generates `spawn_builder()` returning a `SpawnBuilder` with one setter per
+// parameter, and `call()` which checks the mandatory parameters and calls the
+// function. Parameters take the same options as struct fields, and options on
+// the attribute itself are the struct-level ones.
+//
+// Elided lifetimes in parameter types (`&str`, `&[T]`, `Cow<'_, str>`) get
+// their own named lifetimes on the builder, and an elided lifetime in the
+// return type refers to the single parameter lifetime as usual. Generic
+// parameters used only in the return type move to `call()`. `&mut`
+// parameters need the `owned` pattern, which does not clone the arguments.
+//
+// Since `call()` can fail on missing parameters it returns
+// `Result<Output, SpawnBuilderError>`. The function itself stays callable
+// directly.
+
+use derive_builder::builder;
+
+#[derive(Debug, PartialEq)]
+pub struct Child {
+    command_line: String,
+}
+
+#[builder]
+pub fn spawn(
+    executable: String,
+    #[builder(each = "arg")] args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(default = "1")] priority: u8,
+) -> Child {
+    let mut command_line = format!("{} {}", executable, args.join(" "));
+    if let Some(dir) = current_dir {
+        command_line = format!("cd {} && {}", dir, command_line);
+    }
+    Child {
+        command_line: format!("nice -n {} {}", priority, command_line),
+    }
+}
+
+#[builder(pattern = "owned", build_fn(name = "run"))]
+fn greet<T: std::fmt::Display>(name: T, #[builder(setter(into))] greeting: Option<String>) -> String {
+    format!("{}, {}!", greeting.as_deref().unwrap_or("Hello"), name)
+}
+
+#[builder]
+fn first_word<'t>(text: &'t str, #[builder(default = "\" \"")] separator: &str) -> &'t str {
+    text.split(separator).next().unwrap_or(text)
+}
+
+#[builder]
+fn longest<'w>(words: &[&'w str]) -> &'w str {
+    words.iter().copied().max_by_key(|word| word.len()).unwrap_or("")
+}
+
+#[builder]
+fn trim(text: &str) -> &str {
+    text.trim()
+}
+
+#[builder]
+fn parse_all<T: std::str::FromStr>(#[builder(each = "input")] inputs: Vec<&str>) -> Vec<T> {
+    inputs.into_iter().filter_map(|input| input.parse().ok()).collect()
+}
+
+#[builder(pattern = "owned")]
+fn push(target: &mut Vec<u8>, value: u8) {
+    target.push(value);
+}
+
+#[builder]
+fn nothing() {}
+
+fn main() {
+    let child = spawn_builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .arg("--release".to_owned())
+        .current_dir("..".to_owned())
+        .call()
+        .unwrap();
+    assert_eq!(child.command_line, "nice -n 1 cd .. && cargo build --release");
+
+    let err = spawn_builder().call().unwrap_err();
+    assert_eq!(err, SpawnBuilderError::MissingFields(vec!["executable"]));
+
+    let direct = spawn("ls".to_owned(), vec![], None, 5);
+    assert_eq!(direct.command_line, "nice -n 5 ls ");
+
+    let greeting = greet_builder().name("world").run().unwrap();
+    assert_eq!(greeting, "Hello, world!");
+    let greeting = greet_builder().name(42).greeting("Hi").run().unwrap();
+    assert_eq!(greeting, "Hi, 42!");
+
+    let text = String::from("hello big world");
+    let word = first_word_builder().text(&text).call().unwrap();
+    assert_eq!(word, "hello");
+    let word = first_word_builder().text(&text).separator("o").call().unwrap();
+    assert_eq!(word, "hell");
+
+    let words = ["a", "abc", "ab"];
+    let word = longest_builder().words(&words).call().unwrap();
+    assert_eq!(word, "abc");
+
+    let trimmed = trim_builder().text("  padded ").call().unwrap();
+    assert_eq!(trimmed, "padded");
+
+    let numbers = parse_all_builder().input("1").input("x").input("3").call::<u8>().unwrap();
+    assert_eq!(numbers, vec![1, 3]);
+
+    let mut target = vec![1];
+    push_builder().target(&mut target).value(2).call().unwrap();
+    assert_eq!(target, vec![1, 2]);
+
+    nothing_builder().call().unwrap();
+}
@@ -0,0 +1,61 @@
+// #[builder(env = "VAR")] lets `build()` fall back to an environment variable
+// when the field's setter was not called. The value is parsed with `FromStr`
+// (for an Option<T> field, T's), and a parse failure is reported as
+//
+//     CommandBuilderError::InvalidField { field, message: "VAR: <error>" }
+//
+// An explicitly set value wins over the environment, and the environment wins
+// over `default`. A mandatory field is only missing when neither is set.
+//
+// `CommandBuilder::from_env()` pre-populates every annotated field whose
+// variable is set, so the values are visible before `build()`.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    #[builder(env = "BUILDER_TEST_EXECUTABLE")]
+    executable: String,
+    #[builder(env = "BUILDER_TEST_CURRENT_DIR")]
+    current_dir: Option<String>,
+    #[builder(env = "BUILDER_TEST_PRIORITY", default = "1")]
+    priority: u8,
+}
+
+fn main() {
+    let err = Command::builder().build().unwrap_err();
+    assert_eq!(err, CommandBuilderError::MissingFields(vec!["executable"]));
+
+    std::env::set_var("BUILDER_TEST_EXECUTABLE", "cargo");
+    std::env::set_var("BUILDER_TEST_CURRENT_DIR", "/tmp");
+    let command = Command::builder().build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.current_dir.as_deref(), Some("/tmp"));
+    assert_eq!(command.priority, 1);
+
+    // a setter wins over the environment
+    let command = Command::builder().executable("rustc".to_owned()).build().unwrap();
+    assert_eq!(command.executable, "rustc");
+
+    std::env::set_var("BUILDER_TEST_PRIORITY", "7");
+    assert_eq!(Command::builder().build().unwrap().priority, 7);
+
+    std::env::set_var("BUILDER_TEST_PRIORITY", "high");
+    let err = Command::builder().build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value for field `priority`: BUILDER_TEST_PRIORITY: invalid digit found in string"
+    );
+    // the variable is not read at all when the setter was called
+    assert_eq!(Command::builder().priority(3).build().unwrap().priority, 3);
+
+    std::env::set_var("BUILDER_TEST_PRIORITY", "5");
+    let mut builder = CommandBuilder::from_env().unwrap();
+    std::env::remove_var("BUILDER_TEST_EXECUTABLE");
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.priority, 5);
+
+    std::env::set_var("BUILDER_TEST_PRIORITY", "-1");
+    assert!(CommandBuilder::from_env().is_err());
+}
@@ -0,0 +1,20 @@
+// The environment replaces a setter call, so it only applies to fields that
+// have one, and typestate builders cannot defer a mandatory field to runtime.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(compute = "executable.len()", env = "NAME_LEN")]
+    name_len: usize,
+}
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Server {
+    #[builder(env = "SERVER_HOST")]
+    host: String,
+}
+
+fn main() {}
@@ -0,0 +1,118 @@
+// Optional fields can be tied together with constraints checked by `build()`:
+//
+//     #[builder(group = "auth", exactly_one)]   exactly one member must be set
+//     #[builder(group = "out", at_most_one)]    no more than one member is set
+//     #[builder(requires = "password")]         setting this field requires another
+//
+// The kind of a group only has to be written on one of its members. A violated
+// constraint is reported as `GroupConflict`, `GroupUnset` or `MissingRequired`.
+//
+// With #[builder(typestate)] the same constraints are checked at compile time.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Connection {
+    host: String,
+    #[builder(group = "auth", exactly_one)]
+    token: Option<String>,
+    #[builder(group = "auth", requires = "password")]
+    username: Option<String>,
+    password: Option<String>,
+    #[builder(group = "output", at_most_one)]
+    log_file: Option<String>,
+    #[builder(group = "output", default = "false")]
+    quiet: bool,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Request {
+    url: String,
+    #[builder(group = "body", at_most_one)]
+    text: Option<String>,
+    #[builder(group = "body")]
+    bytes: Option<Vec<u8>>,
+    #[builder(requires = "text")]
+    encoding: Option<String>,
+}
+
+fn main() {
+    let conn = Connection::builder()
+        .host("localhost".to_owned())
+        .username("admin".to_owned())
+        .password("secret".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(conn.username.as_deref(), Some("admin"));
+    assert!(conn.token.is_none());
+    assert!(!conn.quiet);
+
+    let err = Connection::builder().host("localhost".to_owned()).build().unwrap_err();
+    assert_eq!(
+        err,
+        ConnectionBuilderError::GroupUnset {
+            group: "auth",
+            fields: vec!["token", "username"],
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "exactly one field of group `auth` must be set: token, username"
+    );
+
+    let err = Connection::builder()
+        .host("localhost".to_owned())
+        .token("t".to_owned())
+        .username("admin".to_owned())
+        .password("secret".to_owned())
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "at most one field of group `auth` can be set, got: token, username"
+    );
+
+    let err = Connection::builder()
+        .host("localhost".to_owned())
+        .username("admin".to_owned())
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ConnectionBuilderError::MissingRequired {
+            field: "username",
+            required: "password",
+        }
+    );
+    assert_eq!(err.to_string(), "field `username` requires `password` to be set");
+
+    let err = Connection::builder()
+        .host("localhost".to_owned())
+        .token("t".to_owned())
+        .log_file("out.log".to_owned())
+        .quiet(true)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ConnectionBuilderError::GroupConflict {
+            group: "output",
+            fields: vec!["log_file", "quiet"],
+        }
+    );
+
+    let request = Request::builder().url("/".to_owned()).build();
+    assert!(request.text.is_none() && request.bytes.is_none());
+
+    let request = Request::builder()
+        .url("/".to_owned())
+        .text("hello".to_owned())
+        .encoding("utf-8".to_owned())
+        .build();
+    assert_eq!(request.text.as_deref(), Some("hello"));
+    assert_eq!(request.encoding.as_deref(), Some("utf-8"));
+
+    let request = Request::builder().bytes(vec![1, 2]).url("/".to_owned()).build();
+    assert_eq!(request.bytes, Some(vec![1, 2]));
+}
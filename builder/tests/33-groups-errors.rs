@@ -0,0 +1,47 @@
+// In typestate mode the group and `requires` constraints are trait bounds on
+// `build()`, so violating them is a compile error naming the group or field.
+// Malformed constraints are rejected when the builder is derived.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Request {
+    #[builder(group = "body", exactly_one)]
+    text: Option<String>,
+    #[builder(group = "body")]
+    bytes: Option<Vec<u8>>,
+    #[builder(requires = "text")]
+    encoding: Option<String>,
+}
+
+#[derive(Builder)]
+pub struct Unresolved {
+    #[builder(group = "auth")]
+    token: Option<String>,
+}
+
+#[derive(Builder)]
+pub struct Mandatory {
+    #[builder(group = "auth", exactly_one)]
+    token: String,
+}
+
+#[derive(Builder)]
+pub struct UnknownField {
+    #[builder(requires = "pasword")]
+    username: Option<String>,
+    password: Option<String>,
+}
+
+#[derive(Builder)]
+pub struct NoGroup {
+    #[builder(at_most_one)]
+    token: Option<String>,
+}
+
+fn main() {
+    let _ = Request::builder().build();
+    let _ = Request::builder().text(String::new()).bytes(Vec::new()).build();
+    let _ = Request::builder().bytes(Vec::new()).encoding(String::new()).build();
+}
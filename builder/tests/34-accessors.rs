@@ -0,0 +1,84 @@
+// Every builder can be inspected before `build()`:
+//
+//     fn executable_ref(&self) -> Option<&String>
+//     fn is_executable_set(&self) -> bool
+//     fn clear_executable(&mut self) -> &mut Self
+//     fn missing_fields(&self) -> Vec<&'static str>
+//
+// `clear_` follows the builder's pattern like the setters do. For a
+// sub-builder field `tls_ref()` returns the nested builder itself, and
+// `missing_fields()` lists the mandatory fields `build()` would report.
+//
+// Typestate builders have the same methods. Clearing a tracked field returns
+// a builder in which that field is unset again.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(sub_builder)]
+    tls: Tls,
+}
+
+#[derive(Builder, Debug)]
+pub struct Tls {
+    cert: String,
+}
+
+#[derive(Builder, Debug)]
+#[builder(pattern = "owned")]
+pub struct Job {
+    name: String,
+    retries: Option<u32>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Request {
+    url: String,
+    method: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    assert_eq!(builder.missing_fields(), vec!["executable"]);
+    assert!(!builder.is_executable_set());
+    assert_eq!(builder.executable_ref(), None);
+
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+    assert!(builder.is_executable_set());
+    assert_eq!(builder.executable_ref().map(String::as_str), Some("cargo"));
+    assert_eq!(builder.args_ref(), Some(&vec!["build".to_owned()]));
+    assert!(builder.missing_fields().is_empty());
+
+    builder.current_dir("/".to_owned()).clear_current_dir().clear_executable();
+    assert!(!builder.is_current_dir_set());
+    assert_eq!(builder.missing_fields(), vec!["executable"]);
+
+    builder.tls(|tls| tls.cert("cert.pem".to_owned()));
+    assert_eq!(builder.tls_ref().cert_ref().map(String::as_str), Some("cert.pem"));
+    builder.clear_tls();
+    assert_eq!(builder.tls_ref().missing_fields(), vec!["cert"]);
+
+    let job = Job::builder().name("a".to_owned()).retries(3).clear_retries();
+    assert!(!job.is_retries_set());
+    let job = job.build().unwrap();
+    assert_eq!(job.retries, None);
+
+    let request = Request::builder();
+    assert_eq!(request.missing_fields(), vec!["url"]);
+    let request = request.url("/".to_owned()).method("GET".to_owned());
+    assert!(request.is_url_set());
+    assert_eq!(request.url_ref().map(String::as_str), Some("/"));
+    assert_eq!(request.method_ref().map(String::as_str), Some("GET"));
+    assert!(request.missing_fields().is_empty());
+
+    let request = request.clear_url();
+    assert_eq!(request.missing_fields(), vec!["url"]);
+    let request = request.url("/index".to_owned()).build();
+    assert_eq!(request.url, "/index");
+}
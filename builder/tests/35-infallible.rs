@@ -0,0 +1,59 @@
+// When nothing can go wrong in `build()` it returns the struct directly
+// instead of a `Result`. That is the case when every field is an Option, has
+// `each` or a default, and there are no `validate`, `env`, `sub_builder`,
+// group or `requires` attributes.
+//
+// #[builder(fallible)] keeps the `Result` signature anyway, so adding a
+// mandatory field later does not break callers. It works for typestate
+// builders as well.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Options {
+    verbose: Option<bool>,
+    #[builder(each = "feature")]
+    features: Vec<String>,
+    #[builder(default = "4")]
+    jobs: u32,
+}
+
+#[derive(Builder, Debug)]
+#[builder(fallible)]
+pub struct Stable {
+    verbose: Option<bool>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate, fallible)]
+pub struct Request {
+    url: String,
+}
+
+// an infallible builder still works as a sub-builder
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(sub_builder)]
+    options: Options,
+}
+
+fn main() {
+    let options: Options = Options::builder().feature("tls".to_owned()).build();
+    assert_eq!(options.features, vec!["tls"]);
+    assert_eq!(options.jobs, 4);
+    assert_eq!(options.verbose, None);
+
+    let stable: Result<Stable, StableBuilderError> = Stable::builder().verbose(true).build();
+    assert_eq!(stable.unwrap().verbose, Some(true));
+
+    let request: Result<Request, RequestBuilderError> = Request::builder().url("/".to_owned()).build();
+    assert_eq!(request.unwrap().url, "/");
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .options(|options| options.jobs(8))
+        .build()
+        .unwrap();
+    assert_eq!(command.options.jobs, 8);
+}
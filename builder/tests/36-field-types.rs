@@ -0,0 +1,47 @@
+// Option is recognised under any of its paths, not just the bare prelude name:
+// `std::option::Option<T>` and `::core::option::Option<T>` fields are optional
+// too.
+//
+// A type alias hides the Option from the macro, so #[builder(optional)] marks
+// such a field as optional by hand; its setter takes the alias' inner type.
+// #[builder(optional = false)] does the opposite for an unrelated type that
+// happens to be named Option.
+
+use derive_builder::Builder;
+
+type MaybePath = Option<String>;
+
+mod custom {
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Option<T>(pub T);
+}
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    current_dir: std::option::Option<String>,
+    priority: ::core::option::Option<u8>,
+    #[builder(optional)]
+    log_file: MaybePath,
+    #[builder(optional = false)]
+    limit: custom::Option<u32>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .log_file("out.log".to_owned())
+        .limit(custom::Option(3))
+        .build()
+        .unwrap();
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.priority, None);
+    assert_eq!(command.log_file.as_deref(), Some("out.log"));
+    assert_eq!(command.limit, custom::Option(3));
+
+    let err = Command::builder().build().unwrap_err();
+    assert_eq!(
+        err,
+        CommandBuilderError::MissingFields(vec!["executable", "limit"])
+    );
+}
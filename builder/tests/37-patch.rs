@@ -0,0 +1,71 @@
+// #[builder(patch)] also generates `CommandPatch`, a partial update of
+// `Command` with every field wrapped in Option: `None` leaves the field as it
+// is, `Some(value)` replaces it. For an Option field `Some(None)` clears it.
+//
+//     command.apply(patch)           applies a patch in place
+//     base.merge(overrides)          layers patches, `overrides` wins
+//     old.diff(&new)                 the patch that turns `old` into `new`
+//
+// The patch gets the same derives as the builder. Fields without a setter
+// (`skip`, `compute`) are not part of it.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Clone, Debug, PartialEq)]
+#[builder(patch, derive(Clone, PartialEq))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(compute = "args.len()")]
+    arg_count: usize,
+}
+
+fn main() {
+    let mut command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("/src".to_owned())
+        .build()
+        .unwrap();
+    let original = command.clone();
+
+    command.apply(CommandPatch {
+        executable: Some("rustc".to_owned()),
+        current_dir: Some(None),
+        ..Default::default()
+    });
+    assert_eq!(command.executable, "rustc");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir, None);
+
+    let patch = original.diff(&command);
+    assert_eq!(
+        patch,
+        CommandPatch {
+            executable: Some("rustc".to_owned()),
+            args: None,
+            current_dir: Some(None),
+        }
+    );
+    assert_eq!(command.diff(&command), CommandPatch::default());
+
+    let mut restored = command.clone();
+    restored.apply(command.diff(&original));
+    assert_eq!(restored, original);
+
+    let base = CommandPatch {
+        executable: Some("cargo".to_owned()),
+        args: Some(vec!["test".to_owned()]),
+        ..Default::default()
+    };
+    let overrides = CommandPatch {
+        args: Some(vec!["check".to_owned()]),
+        ..Default::default()
+    };
+    let merged = base.merge(overrides);
+    assert_eq!(merged.executable.as_deref(), Some("cargo"));
+    assert_eq!(merged.args, Some(vec!["check".to_owned()]));
+    assert_eq!(merged.current_dir, None);
+}
@@ -0,0 +1,119 @@
+// Builders can be filled from key/value sources such as INI files or
+// `--set key=value` flags:
+//
+//     builder.set_by_name("timeout", "30")?;
+//
+// The value is parsed with `FromStr`; a parse failure is reported as
+// `InvalidField` and an unknown name as `UnknownField`. A field with `each`
+// gets one more element per call. Maps with `each` and sub-builders cannot be
+// set this way.
+//
+// `set_by_name` exists only while every field it can set implements
+// `FromStr`. A field of another type, such as `Duration`, is left out with
+// #[builder(by_name = false)] and reported as not settable by name.
+//
+// `CommandBuilder::FIELDS` describes every field with a setter: its name, its
+// type as written in the struct, whether it is required and its doc comment.
+//
+// Raw identifiers are named without their `r#` prefix: a field `r#type` is
+// set with `set_by_name("type", ..)` and reported as `type`.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    /// Program to run.
+    executable: String,
+    /// Arguments, in order.
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: HashMap<String, String>,
+    timeout: Option<u64>,
+    #[builder(by_name = false)]
+    grace: Option<Duration>,
+}
+
+#[derive(Builder, Debug)]
+pub struct Entry {
+    r#type: String,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Request {
+    url: String,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder.set_by_name("executable", "cargo").unwrap();
+    builder.set_by_name("args", "build").unwrap();
+    builder.set_by_name("args", "--release").unwrap();
+    builder.set_by_name("timeout", "30").unwrap();
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.timeout, Some(30));
+    assert!(command.env.is_empty());
+
+    let err = builder.set_by_name("timeout", "soon").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value for field `timeout`: invalid digit found in string"
+    );
+    let err = builder.set_by_name("timout", "30").unwrap_err();
+    assert_eq!(err, CommandBuilderError::UnknownField("timout".to_owned()));
+    assert_eq!(err.to_string(), "unknown field `timout`");
+    let err = builder.set_by_name("env", "A=1").unwrap_err();
+    assert_eq!(err.to_string(), "invalid value for field `env`: cannot be set by name");
+    let err = builder.set_by_name("grace", "5").unwrap_err();
+    assert_eq!(err.to_string(), "invalid value for field `grace`: cannot be set by name");
+
+    assert_eq!(
+        CommandBuilder::FIELDS,
+        &[
+            CommandBuilderFieldMeta {
+                name: "executable",
+                ty: "String",
+                required: true,
+                doc: "Program to run.",
+            },
+            CommandBuilderFieldMeta {
+                name: "args",
+                ty: "Vec<String>",
+                required: false,
+                doc: "Arguments, in order.",
+            },
+            CommandBuilderFieldMeta {
+                name: "env",
+                ty: "HashMap<String, String>",
+                required: false,
+                doc: "",
+            },
+            CommandBuilderFieldMeta {
+                name: "timeout",
+                ty: "Option<u64>",
+                required: false,
+                doc: "",
+            },
+            CommandBuilderFieldMeta {
+                name: "grace",
+                ty: "Option<Duration>",
+                required: false,
+                doc: "",
+            },
+        ]
+    );
+
+    assert_eq!(RequestBuilder::FIELDS[0].name, "url");
+
+    assert_eq!(EntryBuilder::FIELDS[0].name, "type");
+    let mut entry = Entry::builder();
+    let err = entry.build().unwrap_err();
+    assert_eq!(err, EntryBuilderError::MissingFields(vec!["type"]));
+    entry.set_by_name("type", "file").unwrap();
+    assert_eq!(entry.build().unwrap().r#type, "file");
+}
@@ -0,0 +1,75 @@
+// #[builder(const)] makes `builder()`, the setters and `build()` const fns, so
+// configs can be assembled in const context:
+//
+//     const CFG: Command = Command::builder().executable("cargo").build();
+//
+// The setters take the builder by value. A missing required field makes
+// `build()` panic with the field's name, which in const context is a compile
+// error. Field types and `default` expressions must be usable in a const fn,
+// and options that need runtime code (`each`, `setter(into)`, `validate`,
+// `env`, ...) are rejected.
+//
+// Used as a `sub_builder` of an owned builder, a const builder reports its
+// missing fields as a `Nested` error instead of panicking.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(const)]
+pub struct Command {
+    executable: &'static str,
+    current_dir: Option<&'static str>,
+    #[builder(default = "30")]
+    timeout_secs: u64,
+    #[builder(compute = "timeout_secs * 1000")]
+    timeout_ms: u64,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+pub struct Job {
+    name: String,
+    #[builder(sub_builder)]
+    command: Command,
+}
+
+const CARGO: Command = Command::builder().executable("cargo").build();
+
+const TABLE: [Command; 2] = [
+    CARGO,
+    Command::builder()
+        .executable("rustc")
+        .current_dir("/src")
+        .timeout_secs(5)
+        .build(),
+];
+
+fn main() {
+    assert_eq!(CARGO.executable, "cargo");
+    assert_eq!(CARGO.current_dir, None);
+    assert_eq!(CARGO.timeout_ms, 30_000);
+    assert_eq!(TABLE[1].current_dir, Some("/src"));
+    assert_eq!(TABLE[1].timeout_ms, 5_000);
+
+    // at runtime the builder works as usual
+    let command = Command::builder().executable("cargo").build();
+    assert_eq!(command, CARGO);
+
+    let mut job = Job::builder().name("build".to_owned());
+    *job.command_mut() = Command::builder().executable("cargo");
+    assert_eq!(job.build().ok().unwrap().command, CARGO);
+
+    let err = Job::builder().name("build".to_owned()).build().err().unwrap();
+    assert_eq!(
+        err,
+        JobBuilderError::Nested(vec![("command.executable".to_owned(), "missing".to_owned())])
+    );
+
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(|| Command::builder().build());
+    let message = *result.unwrap_err().downcast::<&str>().unwrap();
+    assert_eq!(
+        message,
+        "`CommandBuilder::build()`: missing required field `executable`"
+    );
+}
@@ -0,0 +1,47 @@
+// A const builder reports a missing required field when the constant is
+// evaluated, and rejects options that need code a const fn cannot run.
+// `Default::default()` is not const either, so a bare `default` or `skip`
+// needs an explicit `default = "..."`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(const)]
+pub struct Command {
+    executable: &'static str,
+}
+
+#[derive(Builder)]
+#[builder(const)]
+pub struct Args {
+    #[builder(each = "arg")]
+    args: Vec<&'static str>,
+}
+
+#[derive(Builder)]
+#[builder(const)]
+pub struct Retries {
+    #[builder(default)]
+    retries: u8,
+}
+
+#[derive(Builder)]
+#[builder(const)]
+pub struct Attempts {
+    #[builder(skip, default = "0")]
+    failures: u8,
+    #[builder(skip)]
+    attempts: u8,
+}
+
+#[derive(Builder)]
+#[builder(const, pattern = "mutable")]
+pub struct Mutable {
+    name: &'static str,
+}
+
+const CMD: Command = Command::builder().build();
+
+fn main() {
+    let _ = CMD;
+}
@@ -0,0 +1,18 @@
+// A function builder stores the arguments until `call()`, so it needs a
+// nameable type for each of them: `impl Trait` parameters are rejected. With
+// any pattern but `owned` the arguments are cloned on `call()`, which a
+// `&mut` reference does not allow.
+
+use derive_builder::builder;
+
+#[builder]
+fn show(value: impl std::fmt::Display) -> String {
+    value.to_string()
+}
+
+#[builder]
+fn push(target: &mut Vec<u8>, value: u8) {
+    target.push(value);
+}
+
+fn main() {}
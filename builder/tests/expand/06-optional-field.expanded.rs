@@ -0,0 +1,336 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    args: ::core::option::Option<Vec<String>>,
+    env: ::core::option::Option<Vec<String>>,
+    current_dir: ::core::option::Option<String>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            args: ::core::option::Option::None,
+            env: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn args(&mut self, val: Vec<String>) -> &mut Self {
+        self.args = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn env(&mut self, val: Vec<String>) -> &mut Self {
+        self.env = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn current_dir(&mut self, val: String) -> &mut Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.args)
+    }
+    pub fn is_args_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.args)
+    }
+    pub fn clear_args(&mut self) -> &mut Self {
+        self.args = ::core::option::Option::None;
+        self
+    }
+    pub fn env_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.env)
+    }
+    pub fn is_env_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.env)
+    }
+    pub fn clear_env(&mut self) -> &mut Self {
+        self.env = ::core::option::Option::None;
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(&mut self) -> &mut Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if self.args.is_none() {
+            missing.push("args");
+        }
+        if self.env.is_none() {
+            missing.push("env");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "env",
+            ty: "Vec<String>",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> Vec<String>: ::core::str::FromStr,
+        for<'__a> <Vec<String> as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> Vec<String>: ::core::str::FromStr,
+        for<'__a> <Vec<String> as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "args" => {
+                self.args = ::core::option::Option::Some(
+                    match <Vec<String> as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "args",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "env" => {
+                self.env = ::core::option::Option::Some(
+                    match <Vec<String> as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "env",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "current_dir" => {
+                self.current_dir = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "current_dir",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if self.args.is_none() {
+            missing.push("args");
+        }
+        if self.env.is_none() {
+            missing.push("env");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let args = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.args),
+        );
+        let env = ::core::option::Option::unwrap(::core::clone::Clone::clone(&self.env));
+        let current_dir = ::core::clone::Clone::clone(&self.current_dir);
+        let __built = Command {
+            executable,
+            args,
+            env,
+            current_dir,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            args: ::core::option::Option::Some(value.args),
+            env: ::core::option::Option::Some(value.env),
+            current_dir: value.current_dir,
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            args: ::core::option::Option::Some(::core::clone::Clone::clone(&self.args)),
+            env: ::core::option::Option::Some(::core::clone::Clone::clone(&self.env)),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,3 @@
+::core::compile_error! {
+    "expected `builder(each = \"...\")`"
+}
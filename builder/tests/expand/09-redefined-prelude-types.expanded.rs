@@ -0,0 +1,191 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let __built = Command { executable };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,288 @@
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder<__executable = (), __args = (), __env = ()> {
+    executable: __executable,
+    args: __args,
+    env: __env,
+    current_dir: ::core::option::Option<String>,
+    __marker: ::core::marker::PhantomData<fn() -> Command>,
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        CommandBuilder {
+            executable: (),
+            args: (),
+            env: (),
+            current_dir: ::core::option::Option::None,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__executable, __args, __env> CommandBuilder<__executable, __args, __env> {
+    pub fn executable(self, val: String) -> CommandBuilder<(String,), __args, __env> {
+        CommandBuilder {
+            executable: (val,),
+            args: self.args,
+            env: self.env,
+            current_dir: self.current_dir,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn args(
+        self,
+        val: Vec<String>,
+    ) -> CommandBuilder<__executable, (Vec<String>,), __env> {
+        CommandBuilder {
+            executable: self.executable,
+            args: (val,),
+            env: self.env,
+            current_dir: self.current_dir,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn env(
+        self,
+        val: Vec<String>,
+    ) -> CommandBuilder<__executable, __args, (Vec<String>,)> {
+        CommandBuilder {
+            executable: self.executable,
+            args: self.args,
+            env: (val,),
+            current_dir: self.current_dir,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn current_dir(mut self, val: String) -> Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(mut self) -> Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+}
+impl CommandBuilder {
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "env",
+            ty: "Vec<String>",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Command>
+for CommandBuilder<(String,), (Vec<String>,), (Vec<String>,)> {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: (value.executable,),
+            args: (value.args,),
+            env: (value.env,),
+            current_dir: value.current_dir,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder<(String,), (Vec<String>,), (Vec<String>,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: (::core::clone::Clone::clone(&self.executable),),
+            args: (::core::clone::Clone::clone(&self.args),),
+            env: (::core::clone::Clone::clone(&self.env),),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`CommandBuilder::build()` requires field `executable` to be set",
+        label = "call `.executable(..)` before `.build()`"
+    )]
+    pub trait CommandBuilder_executable_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> CommandBuilder_executable_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`CommandBuilder::build()` requires field `args` to be set",
+        label = "call `.args(..)` before `.build()`"
+    )]
+    pub trait CommandBuilder_args_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> CommandBuilder_args_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`CommandBuilder::build()` requires field `env` to be set",
+        label = "call `.env(..)` before `.build()`"
+    )]
+    pub trait CommandBuilder_env_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> CommandBuilder_env_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait CommandBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> CommandBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> CommandBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__executable, __args, __env> CommandBuilder<__executable, __args, __env>
+    where
+        __executable: CommandBuilder_maybe_set<String>,
+        __args: CommandBuilder_maybe_set<Vec<String>>,
+        __env: CommandBuilder_maybe_set<Vec<String>>,
+    {
+        pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+            CommandBuilder_maybe_set::<String>::value_ref(&self.executable)
+        }
+        pub fn is_executable_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.executable_ref())
+        }
+        pub fn clear_executable(self) -> CommandBuilder<(), __args, __env> {
+            CommandBuilder {
+                executable: (),
+                args: self.args,
+                env: self.env,
+                current_dir: self.current_dir,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+            CommandBuilder_maybe_set::<Vec<String>>::value_ref(&self.args)
+        }
+        pub fn is_args_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.args_ref())
+        }
+        pub fn clear_args(self) -> CommandBuilder<__executable, (), __env> {
+            CommandBuilder {
+                executable: self.executable,
+                args: (),
+                env: self.env,
+                current_dir: self.current_dir,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn env_ref(&self) -> ::core::option::Option<&Vec<String>> {
+            CommandBuilder_maybe_set::<Vec<String>>::value_ref(&self.env)
+        }
+        pub fn is_env_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.env_ref())
+        }
+        pub fn clear_env(self) -> CommandBuilder<__executable, __args, ()> {
+            CommandBuilder {
+                executable: self.executable,
+                args: self.args,
+                env: (),
+                current_dir: self.current_dir,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_executable_set() {
+                missing.push("executable");
+            }
+            if !self.is_args_set() {
+                missing.push("args");
+            }
+            if !self.is_env_set() {
+                missing.push("env");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__executable, __args, __env> CommandBuilder<__executable, __args, __env> {
+        pub fn build(self) -> Command
+        where
+            __executable: CommandBuilder_executable_is_set<String>,
+            __args: CommandBuilder_args_is_set<Vec<String>>,
+            __env: CommandBuilder_env_is_set<Vec<String>>,
+        {
+            let executable = CommandBuilder_executable_is_set::value(self.executable);
+            let args = CommandBuilder_args_is_set::value(self.args);
+            let env = CommandBuilder_env_is_set::value(self.env);
+            let current_dir = self.current_dir;
+            Command {
+                executable,
+                args,
+                env,
+                current_dir,
+            }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,222 @@
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder<__executable = (), __args = ()> {
+    executable: __executable,
+    args: __args,
+    current_dir: ::core::option::Option<String>,
+    __marker: ::core::marker::PhantomData<fn() -> Command>,
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        CommandBuilder {
+            executable: (),
+            args: (),
+            current_dir: ::core::option::Option::None,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__executable, __args> CommandBuilder<__executable, __args> {
+    pub fn executable(self, val: String) -> CommandBuilder<(String,), __args> {
+        CommandBuilder {
+            executable: (val,),
+            args: self.args,
+            current_dir: self.current_dir,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn args(self, val: Vec<String>) -> CommandBuilder<__executable, (Vec<String>,)> {
+        CommandBuilder {
+            executable: self.executable,
+            args: (val,),
+            current_dir: self.current_dir,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn current_dir(mut self, val: String) -> Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(mut self) -> Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+}
+impl CommandBuilder {
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Command> for CommandBuilder<(String,), (Vec<String>,)> {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: (value.executable,),
+            args: (value.args,),
+            current_dir: value.current_dir,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder<(String,), (Vec<String>,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: (::core::clone::Clone::clone(&self.executable),),
+            args: (::core::clone::Clone::clone(&self.args),),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`CommandBuilder::build()` requires field `executable` to be set",
+        label = "call `.executable(..)` before `.build()`"
+    )]
+    pub trait CommandBuilder_executable_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> CommandBuilder_executable_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`CommandBuilder::build()` requires field `args` to be set",
+        label = "call `.args(..)` before `.build()`"
+    )]
+    pub trait CommandBuilder_args_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> CommandBuilder_args_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait CommandBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> CommandBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> CommandBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__executable, __args> CommandBuilder<__executable, __args>
+    where
+        __executable: CommandBuilder_maybe_set<String>,
+        __args: CommandBuilder_maybe_set<Vec<String>>,
+    {
+        pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+            CommandBuilder_maybe_set::<String>::value_ref(&self.executable)
+        }
+        pub fn is_executable_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.executable_ref())
+        }
+        pub fn clear_executable(self) -> CommandBuilder<(), __args> {
+            CommandBuilder {
+                executable: (),
+                args: self.args,
+                current_dir: self.current_dir,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+            CommandBuilder_maybe_set::<Vec<String>>::value_ref(&self.args)
+        }
+        pub fn is_args_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.args_ref())
+        }
+        pub fn clear_args(self) -> CommandBuilder<__executable, ()> {
+            CommandBuilder {
+                executable: self.executable,
+                args: (),
+                current_dir: self.current_dir,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_executable_set() {
+                missing.push("executable");
+            }
+            if !self.is_args_set() {
+                missing.push("args");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__executable, __args> CommandBuilder<__executable, __args> {
+        pub fn build(self) -> Command
+        where
+            __executable: CommandBuilder_executable_is_set<String>,
+            __args: CommandBuilder_args_is_set<Vec<String>>,
+        {
+            let executable = CommandBuilder_executable_is_set::value(self.executable);
+            let args = CommandBuilder_args_is_set::value(self.args);
+            let current_dir = self.current_dir;
+            Command {
+                executable,
+                args,
+                current_dir,
+            }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
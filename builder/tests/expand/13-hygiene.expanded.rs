@@ -0,0 +1,692 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    current_dir: ::core::option::Option<String>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn current_dir(&mut self, val: String) -> &mut Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(&mut self) -> &mut Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "current_dir" => {
+                self.current_dir = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "current_dir",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let current_dir = ::core::clone::Clone::clone(&self.current_dir);
+        let __built = Command { executable, current_dir };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            current_dir: value.current_dir,
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct ProcessBuilder {
+    pid: ::core::option::Option<u32>,
+}
+impl ::core::default::Default for ProcessBuilder {
+    fn default() -> Self {
+        ProcessBuilder {
+            pid: ::core::option::Option::None,
+        }
+    }
+}
+impl Process {
+    pub fn builder() -> ProcessBuilder {
+        <ProcessBuilder as ::core::default::Default>::default()
+    }
+}
+impl ProcessBuilder {
+    pub fn pid(&mut self, val: u32) -> &mut Self {
+        self.pid = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn pid_ref(&self) -> ::core::option::Option<&u32> {
+        ::core::option::Option::as_ref(&self.pid)
+    }
+    pub fn is_pid_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.pid)
+    }
+    pub fn clear_pid(&mut self) -> &mut Self {
+        self.pid = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.pid.is_none() {
+            missing.push("pid");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [ProcessBuilderFieldMeta] = &[
+        ProcessBuilderFieldMeta {
+            name: "pid",
+            ty: "u32",
+            required: true,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), ProcessBuilderError>
+    where
+        for<'__a> u32: ::core::str::FromStr,
+        for<'__a> <u32 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "pid" => {
+                self.pid = ::core::option::Option::Some(
+                    match <u32 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(ProcessBuilderError::InvalidField {
+                                field: "pid",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    ProcessBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Process, ProcessBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.pid.is_none() {
+            missing.push("pid");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                ProcessBuilderError::MissingFields(missing),
+            );
+        }
+        let pid = ::core::option::Option::unwrap(::core::clone::Clone::clone(&self.pid));
+        let __built = Process { pid };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Process, ProcessBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Process> for ProcessBuilder {
+    fn from(value: Process) -> Self {
+        ProcessBuilder {
+            pid: ::core::option::Option::Some(value.pid),
+        }
+    }
+}
+impl Process {
+    pub fn to_builder(&self) -> ProcessBuilder
+    where
+        for<'__a> u32: ::core::clone::Clone,
+    {
+        ProcessBuilder {
+            pid: ::core::option::Option::Some(::core::clone::Clone::clone(&self.pid)),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum ProcessBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl ProcessBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            ProcessBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            ProcessBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            ProcessBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for ProcessBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ProcessBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            ProcessBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            ProcessBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for ProcessBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct ProcessBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct JobBuilder<__name = ()> {
+    name: __name,
+    __marker: ::core::marker::PhantomData<fn() -> Job>,
+}
+impl Job {
+    pub fn builder() -> JobBuilder {
+        JobBuilder {
+            name: (),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__name> JobBuilder<__name> {
+    pub fn name(self, val: String) -> JobBuilder<(String,)> {
+        JobBuilder {
+            name: (val,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl JobBuilder {
+    pub const FIELDS: &'static [JobBuilderFieldMeta] = &[
+        JobBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Job> for JobBuilder<(String,)> {
+    fn from(value: Job) -> Self {
+        JobBuilder {
+            name: (value.name,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Job {
+    pub fn to_builder(&self) -> JobBuilder<(String,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+    {
+        JobBuilder {
+            name: (::core::clone::Clone::clone(&self.name),),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`JobBuilder::build()` requires field `name` to be set",
+        label = "call `.name(..)` before `.build()`"
+    )]
+    pub trait JobBuilder_name_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> JobBuilder_name_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait JobBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> JobBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> JobBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__name> JobBuilder<__name>
+    where
+        __name: JobBuilder_maybe_set<String>,
+    {
+        pub fn name_ref(&self) -> ::core::option::Option<&String> {
+            JobBuilder_maybe_set::<String>::value_ref(&self.name)
+        }
+        pub fn is_name_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.name_ref())
+        }
+        pub fn clear_name(self) -> JobBuilder<()> {
+            JobBuilder {
+                name: (),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_name_set() {
+                missing.push("name");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__name> JobBuilder<__name> {
+        pub fn build(self) -> Job
+        where
+            __name: JobBuilder_name_is_set<String>,
+        {
+            let name = JobBuilder_name_is_set::value(self.name);
+            Job { name }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct JobBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct TaskBuilder<__name = ()> {
+    name: __name,
+    __marker: ::core::marker::PhantomData<fn() -> Task>,
+}
+impl Task {
+    pub fn builder() -> TaskBuilder {
+        TaskBuilder {
+            name: (),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__name> TaskBuilder<__name> {
+    pub fn name(self, val: String) -> TaskBuilder<(String,)> {
+        TaskBuilder {
+            name: (val,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl TaskBuilder {
+    pub const FIELDS: &'static [TaskBuilderFieldMeta] = &[
+        TaskBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Task> for TaskBuilder<(String,)> {
+    fn from(value: Task) -> Self {
+        TaskBuilder {
+            name: (value.name,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Task {
+    pub fn to_builder(&self) -> TaskBuilder<(String,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+    {
+        TaskBuilder {
+            name: (::core::clone::Clone::clone(&self.name),),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`TaskBuilder::build()` requires field `name` to be set",
+        label = "call `.name(..)` before `.build()`"
+    )]
+    pub trait TaskBuilder_name_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> TaskBuilder_name_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait TaskBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> TaskBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> TaskBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__name> TaskBuilder<__name>
+    where
+        __name: TaskBuilder_maybe_set<String>,
+    {
+        pub fn name_ref(&self) -> ::core::option::Option<&String> {
+            TaskBuilder_maybe_set::<String>::value_ref(&self.name)
+        }
+        pub fn is_name_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.name_ref())
+        }
+        pub fn clear_name(self) -> TaskBuilder<()> {
+            TaskBuilder {
+                name: (),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_name_set() {
+                missing.push("name");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__name> TaskBuilder<__name> {
+        pub fn build(self) -> Task
+        where
+            __name: TaskBuilder_name_is_set<String>,
+        {
+            let name = TaskBuilder_name_is_set::value(self.name);
+            Task { name }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct TaskBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,760 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    args: ::core::option::Option<Vec<String>>,
+    env: ::core::option::Option<HashMap<String, String>>,
+    labels: ::core::option::Option<BTreeMap<String, u32>>,
+    features: ::core::option::Option<HashSet<String>>,
+    stages: ::core::option::Option<VecDeque<u8>>,
+    weights: ::core::option::Option<Bag>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            args: ::core::option::Option::None,
+            env: ::core::option::Option::None,
+            labels: ::core::option::Option::None,
+            features: ::core::option::Option::None,
+            stages: ::core::option::Option::None,
+            weights: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn args(&mut self, val: Vec<String>) -> &mut Self {
+        self.args = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn arg(&mut self, val: String) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_args<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn env(&mut self, key: String, value: String) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.env.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once((key, value)),
+        );
+        self
+    }
+    pub fn extend_env<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = (String, String)>,
+    {
+        ::core::iter::Extend::extend(
+            self.env.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn labels(&mut self, val: BTreeMap<String, u32>) -> &mut Self {
+        self.labels = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn label(&mut self, key: String, value: u32) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.labels.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once((key, value)),
+        );
+        self
+    }
+    pub fn extend_labels<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = (String, u32)>,
+    {
+        ::core::iter::Extend::extend(
+            self.labels.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn features(&mut self, val: HashSet<String>) -> &mut Self {
+        self.features = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn feature(&mut self, val: String) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.features.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_features<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.features.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn stages(&mut self, val: VecDeque<u8>) -> &mut Self {
+        self.stages = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn stage(&mut self, val: u8) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.stages.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_stages<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = u8>,
+    {
+        ::core::iter::Extend::extend(
+            self.stages.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn weights(&mut self, val: Bag) -> &mut Self {
+        self.weights = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn weight(
+        &mut self,
+        val: <Bag as ::core::iter::IntoIterator>::Item,
+    ) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.weights.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_weights<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<
+            Item = <Bag as ::core::iter::IntoIterator>::Item,
+        >,
+    {
+        ::core::iter::Extend::extend(
+            self.weights.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.args)
+    }
+    pub fn is_args_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.args)
+    }
+    pub fn clear_args(&mut self) -> &mut Self {
+        self.args = ::core::option::Option::None;
+        self
+    }
+    pub fn env_ref(&self) -> ::core::option::Option<&HashMap<String, String>> {
+        ::core::option::Option::as_ref(&self.env)
+    }
+    pub fn is_env_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.env)
+    }
+    pub fn clear_env(&mut self) -> &mut Self {
+        self.env = ::core::option::Option::None;
+        self
+    }
+    pub fn labels_ref(&self) -> ::core::option::Option<&BTreeMap<String, u32>> {
+        ::core::option::Option::as_ref(&self.labels)
+    }
+    pub fn is_labels_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.labels)
+    }
+    pub fn clear_labels(&mut self) -> &mut Self {
+        self.labels = ::core::option::Option::None;
+        self
+    }
+    pub fn features_ref(&self) -> ::core::option::Option<&HashSet<String>> {
+        ::core::option::Option::as_ref(&self.features)
+    }
+    pub fn is_features_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.features)
+    }
+    pub fn clear_features(&mut self) -> &mut Self {
+        self.features = ::core::option::Option::None;
+        self
+    }
+    pub fn stages_ref(&self) -> ::core::option::Option<&VecDeque<u8>> {
+        ::core::option::Option::as_ref(&self.stages)
+    }
+    pub fn is_stages_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.stages)
+    }
+    pub fn clear_stages(&mut self) -> &mut Self {
+        self.stages = ::core::option::Option::None;
+        self
+    }
+    pub fn weights_ref(&self) -> ::core::option::Option<&Bag> {
+        ::core::option::Option::as_ref(&self.weights)
+    }
+    pub fn is_weights_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.weights)
+    }
+    pub fn clear_weights(&mut self) -> &mut Self {
+        self.weights = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "env",
+            ty: "HashMap<String, String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "labels",
+            ty: "BTreeMap<String, u32>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "features",
+            ty: "HashSet<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "stages",
+            ty: "VecDeque<u8>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "weights",
+            ty: "Bag",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u8: ::core::str::FromStr,
+        for<'__a> <u8 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> <Bag as ::core::iter::IntoIterator>::Item: ::core::str::FromStr,
+        for<'__a> <<Bag as ::core::iter::IntoIterator>::Item as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "args" => {
+                ::core::iter::Extend::extend(
+                    self.args.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "args",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "env" => {
+                ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "env",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "labels" => {
+                ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "labels",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "features" => {
+                ::core::iter::Extend::extend(
+                    self.features.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "features",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "stages" => {
+                ::core::iter::Extend::extend(
+                    self.stages.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <u8 as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "stages",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "weights" => {
+                ::core::iter::Extend::extend(
+                    self.weights.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <<Bag as ::core::iter::IntoIterator>::Item as ::core::str::FromStr>::from_str(
+                            value,
+                        ) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "weights",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let args = match ::core::clone::Clone::clone(&self.args) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let env = match ::core::clone::Clone::clone(&self.env) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let labels = match ::core::clone::Clone::clone(&self.labels) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let features = match ::core::clone::Clone::clone(&self.features) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let stages = match ::core::clone::Clone::clone(&self.stages) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let weights = match ::core::clone::Clone::clone(&self.weights) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let __built = Command {
+            executable,
+            args,
+            env,
+            labels,
+            features,
+            stages,
+            weights,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            args: ::core::option::Option::Some(value.args),
+            env: ::core::option::Option::Some(value.env),
+            labels: ::core::option::Option::Some(value.labels),
+            features: ::core::option::Option::Some(value.features),
+            stages: ::core::option::Option::Some(value.stages),
+            weights: ::core::option::Option::Some(value.weights),
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> HashMap<String, String>: ::core::clone::Clone,
+        for<'__a> BTreeMap<String, u32>: ::core::clone::Clone,
+        for<'__a> HashSet<String>: ::core::clone::Clone,
+        for<'__a> VecDeque<u8>: ::core::clone::Clone,
+        for<'__a> Bag: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            args: ::core::option::Option::Some(::core::clone::Clone::clone(&self.args)),
+            env: ::core::option::Option::Some(::core::clone::Clone::clone(&self.env)),
+            labels: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.labels),
+            ),
+            features: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.features),
+            ),
+            stages: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.stages),
+            ),
+            weights: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.weights),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct JobBuilder<__name = ()> {
+    name: __name,
+    steps: ::core::option::Option<Vec<String>>,
+    __marker: ::core::marker::PhantomData<fn() -> Job>,
+}
+impl Job {
+    pub fn builder() -> JobBuilder {
+        JobBuilder {
+            name: (),
+            steps: ::core::option::Option::None,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__name> JobBuilder<__name> {
+    pub fn name(self, val: String) -> JobBuilder<(String,)> {
+        JobBuilder {
+            name: (val,),
+            steps: self.steps,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn steps(mut self, val: Vec<String>) -> Self {
+        self.steps = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn step(mut self, val: String) -> Self {
+        ::core::iter::Extend::extend(
+            self.steps.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_steps<__I>(mut self, iter: __I) -> Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.steps.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn steps_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.steps)
+    }
+    pub fn is_steps_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.steps)
+    }
+    pub fn clear_steps(mut self) -> Self {
+        self.steps = ::core::option::Option::None;
+        self
+    }
+}
+impl JobBuilder {
+    pub const FIELDS: &'static [JobBuilderFieldMeta] = &[
+        JobBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        JobBuilderFieldMeta {
+            name: "steps",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Job> for JobBuilder<(String,)> {
+    fn from(value: Job) -> Self {
+        JobBuilder {
+            name: (value.name,),
+            steps: ::core::option::Option::Some(value.steps),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Job {
+    pub fn to_builder(&self) -> JobBuilder<(String,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+    {
+        JobBuilder {
+            name: (::core::clone::Clone::clone(&self.name),),
+            steps: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.steps),
+            ),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`JobBuilder::build()` requires field `name` to be set",
+        label = "call `.name(..)` before `.build()`"
+    )]
+    pub trait JobBuilder_name_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> JobBuilder_name_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait JobBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> JobBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> JobBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__name> JobBuilder<__name>
+    where
+        __name: JobBuilder_maybe_set<String>,
+    {
+        pub fn name_ref(&self) -> ::core::option::Option<&String> {
+            JobBuilder_maybe_set::<String>::value_ref(&self.name)
+        }
+        pub fn is_name_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.name_ref())
+        }
+        pub fn clear_name(self) -> JobBuilder<()> {
+            JobBuilder {
+                name: (),
+                steps: self.steps,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_name_set() {
+                missing.push("name");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__name> JobBuilder<__name> {
+        pub fn build(self) -> Job
+        where
+            __name: JobBuilder_name_is_set<String>,
+        {
+            let name = JobBuilder_name_is_set::value(self.name);
+            let steps = match self.steps {
+                ::core::option::Option::Some(__value) => __value,
+                ::core::option::Option::None => ::core::default::Default::default(),
+            };
+            Job { name, steps }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct JobBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,907 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    args: ::core::option::Option<Vec<String>>,
+    timeout_secs: ::core::option::Option<u64>,
+    retries: ::core::option::Option<u64>,
+    path: ::core::option::Option<String>,
+    current_dir: ::core::option::Option<String>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            args: ::core::option::Option::None,
+            timeout_secs: ::core::option::Option::None,
+            retries: ::core::option::Option::None,
+            path: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn args(&mut self, val: Vec<String>) -> &mut Self {
+        self.args = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn timeout_secs(&mut self, val: u64) -> &mut Self {
+        self.timeout_secs = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn retries(&mut self, val: u64) -> &mut Self {
+        self.retries = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn path(&mut self, val: String) -> &mut Self {
+        self.path = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn current_dir(&mut self, val: String) -> &mut Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.args)
+    }
+    pub fn is_args_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.args)
+    }
+    pub fn clear_args(&mut self) -> &mut Self {
+        self.args = ::core::option::Option::None;
+        self
+    }
+    pub fn timeout_secs_ref(&self) -> ::core::option::Option<&u64> {
+        ::core::option::Option::as_ref(&self.timeout_secs)
+    }
+    pub fn is_timeout_secs_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.timeout_secs)
+    }
+    pub fn clear_timeout_secs(&mut self) -> &mut Self {
+        self.timeout_secs = ::core::option::Option::None;
+        self
+    }
+    pub fn retries_ref(&self) -> ::core::option::Option<&u64> {
+        ::core::option::Option::as_ref(&self.retries)
+    }
+    pub fn is_retries_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.retries)
+    }
+    pub fn clear_retries(&mut self) -> &mut Self {
+        self.retries = ::core::option::Option::None;
+        self
+    }
+    pub fn path_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.path)
+    }
+    pub fn is_path_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.path)
+    }
+    pub fn clear_path(&mut self) -> &mut Self {
+        self.path = ::core::option::Option::None;
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(&mut self) -> &mut Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "timeout_secs",
+            ty: "u64",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "retries",
+            ty: "u64",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "path",
+            ty: "String",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> Vec<String>: ::core::str::FromStr,
+        for<'__a> <Vec<String> as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u64: ::core::str::FromStr,
+        for<'__a> <u64 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u64: ::core::str::FromStr,
+        for<'__a> <u64 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "args" => {
+                self.args = ::core::option::Option::Some(
+                    match <Vec<String> as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "args",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "timeout_secs" => {
+                self.timeout_secs = ::core::option::Option::Some(
+                    match <u64 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "timeout_secs",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "retries" => {
+                self.retries = ::core::option::Option::Some(
+                    match <u64 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "retries",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "path" => {
+                self.path = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "path",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "current_dir" => {
+                self.current_dir = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "current_dir",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let args = match ::core::clone::Clone::clone(&self.args) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let timeout_secs = match ::core::clone::Clone::clone(&self.timeout_secs) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => 30,
+        };
+        let retries = match ::core::clone::Clone::clone(&self.retries) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => timeout_secs / 10,
+        };
+        let path = match ::core::clone::Clone::clone(&self.path) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => format!("/usr/bin/{}", executable),
+        };
+        let current_dir = match ::core::clone::Clone::clone(&self.current_dir) {
+            ::core::option::Option::Some(__value) => {
+                ::core::option::Option::Some(__value)
+            }
+            ::core::option::Option::None => Some("..".to_owned()),
+        };
+        let __built = Command {
+            executable,
+            args,
+            timeout_secs,
+            retries,
+            path,
+            current_dir,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            args: ::core::option::Option::Some(value.args),
+            timeout_secs: ::core::option::Option::Some(value.timeout_secs),
+            retries: ::core::option::Option::Some(value.retries),
+            path: ::core::option::Option::Some(value.path),
+            current_dir: value.current_dir,
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> u64: ::core::clone::Clone,
+        for<'__a> u64: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            args: ::core::option::Option::Some(::core::clone::Clone::clone(&self.args)),
+            timeout_secs: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.timeout_secs),
+            ),
+            retries: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.retries),
+            ),
+            path: ::core::option::Option::Some(::core::clone::Clone::clone(&self.path)),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct LimitsBuilder {
+    cpus: ::core::option::Option<u32>,
+    memory_mb: ::core::option::Option<u32>,
+    threads: ::core::option::Option<u32>,
+    label: ::core::option::Option<String>,
+}
+impl ::core::default::Default for LimitsBuilder {
+    fn default() -> Self {
+        LimitsBuilder {
+            cpus: ::core::option::Option::None,
+            memory_mb: ::core::option::Option::None,
+            threads: ::core::option::Option::None,
+            label: ::core::option::Option::None,
+        }
+    }
+}
+impl Limits {
+    pub fn builder() -> LimitsBuilder {
+        <LimitsBuilder as ::core::default::Default>::default()
+    }
+}
+impl LimitsBuilder {
+    pub fn cpus(&mut self, val: u32) -> &mut Self {
+        self.cpus = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn memory_mb(&mut self, val: u32) -> &mut Self {
+        self.memory_mb = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn threads(&mut self, val: u32) -> &mut Self {
+        self.threads = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn label(&mut self, val: String) -> &mut Self {
+        self.label = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn cpus_ref(&self) -> ::core::option::Option<&u32> {
+        ::core::option::Option::as_ref(&self.cpus)
+    }
+    pub fn is_cpus_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.cpus)
+    }
+    pub fn clear_cpus(&mut self) -> &mut Self {
+        self.cpus = ::core::option::Option::None;
+        self
+    }
+    pub fn memory_mb_ref(&self) -> ::core::option::Option<&u32> {
+        ::core::option::Option::as_ref(&self.memory_mb)
+    }
+    pub fn is_memory_mb_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.memory_mb)
+    }
+    pub fn clear_memory_mb(&mut self) -> &mut Self {
+        self.memory_mb = ::core::option::Option::None;
+        self
+    }
+    pub fn threads_ref(&self) -> ::core::option::Option<&u32> {
+        ::core::option::Option::as_ref(&self.threads)
+    }
+    pub fn is_threads_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.threads)
+    }
+    pub fn clear_threads(&mut self) -> &mut Self {
+        self.threads = ::core::option::Option::None;
+        self
+    }
+    pub fn label_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.label)
+    }
+    pub fn is_label_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.label)
+    }
+    pub fn clear_label(&mut self) -> &mut Self {
+        self.label = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        missing
+    }
+    pub const FIELDS: &'static [LimitsBuilderFieldMeta] = &[
+        LimitsBuilderFieldMeta {
+            name: "cpus",
+            ty: "u32",
+            required: false,
+            doc: "",
+        },
+        LimitsBuilderFieldMeta {
+            name: "memory_mb",
+            ty: "u32",
+            required: false,
+            doc: "",
+        },
+        LimitsBuilderFieldMeta {
+            name: "threads",
+            ty: "u32",
+            required: false,
+            doc: "",
+        },
+        LimitsBuilderFieldMeta {
+            name: "label",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), LimitsBuilderError>
+    where
+        for<'__a> u32: ::core::str::FromStr,
+        for<'__a> <u32 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u32: ::core::str::FromStr,
+        for<'__a> <u32 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u32: ::core::str::FromStr,
+        for<'__a> <u32 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "cpus" => {
+                self.cpus = ::core::option::Option::Some(
+                    match <u32 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(LimitsBuilderError::InvalidField {
+                                field: "cpus",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "memory_mb" => {
+                self.memory_mb = ::core::option::Option::Some(
+                    match <u32 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(LimitsBuilderError::InvalidField {
+                                field: "memory_mb",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "threads" => {
+                self.threads = ::core::option::Option::Some(
+                    match <u32 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(LimitsBuilderError::InvalidField {
+                                field: "threads",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "label" => {
+                self.label = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(LimitsBuilderError::InvalidField {
+                                field: "label",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    LimitsBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> Limits {
+        let __default = <Limits as ::core::default::Default>::default();
+        let cpus = match ::core::clone::Clone::clone(&self.cpus) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => __default.cpus,
+        };
+        let memory_mb = match ::core::clone::Clone::clone(&self.memory_mb) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => __default.memory_mb,
+        };
+        let threads = match ::core::clone::Clone::clone(&self.threads) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => cpus * 2,
+        };
+        let label = match ::core::clone::Clone::clone(&self.label) {
+            ::core::option::Option::Some(__value) => {
+                ::core::option::Option::Some(__value)
+            }
+            ::core::option::Option::None => __default.label,
+        };
+        Limits {
+            cpus,
+            memory_mb,
+            threads,
+            label,
+        }
+    }
+    #[doc(hidden)]
+    pub fn __try_build(&mut self) -> ::core::result::Result<Limits, LimitsBuilderError> {
+        ::core::result::Result::Ok(self.build())
+    }
+}
+impl ::core::convert::From<Limits> for LimitsBuilder {
+    fn from(value: Limits) -> Self {
+        LimitsBuilder {
+            cpus: ::core::option::Option::Some(value.cpus),
+            memory_mb: ::core::option::Option::Some(value.memory_mb),
+            threads: ::core::option::Option::Some(value.threads),
+            label: value.label,
+        }
+    }
+}
+impl Limits {
+    pub fn to_builder(&self) -> LimitsBuilder
+    where
+        for<'__a> u32: ::core::clone::Clone,
+        for<'__a> u32: ::core::clone::Clone,
+        for<'__a> u32: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+    {
+        LimitsBuilder {
+            cpus: ::core::option::Option::Some(::core::clone::Clone::clone(&self.cpus)),
+            memory_mb: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.memory_mb),
+            ),
+            threads: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.threads),
+            ),
+            label: ::core::clone::Clone::clone(&self.label),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum LimitsBuilderError {
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl LimitsBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            LimitsBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            LimitsBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for LimitsBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            LimitsBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            LimitsBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for LimitsBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct LimitsBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct JobBuilder<__name = ()> {
+    name: __name,
+    weight: ::core::option::Option<usize>,
+    __marker: ::core::marker::PhantomData<fn() -> Job>,
+}
+impl Job {
+    pub fn builder() -> JobBuilder {
+        JobBuilder {
+            name: (),
+            weight: ::core::option::Option::None,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__name> JobBuilder<__name> {
+    pub fn name(self, val: String) -> JobBuilder<(String,)> {
+        JobBuilder {
+            name: (val,),
+            weight: self.weight,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn weight(mut self, val: usize) -> Self {
+        self.weight = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn weight_ref(&self) -> ::core::option::Option<&usize> {
+        ::core::option::Option::as_ref(&self.weight)
+    }
+    pub fn is_weight_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.weight)
+    }
+    pub fn clear_weight(mut self) -> Self {
+        self.weight = ::core::option::Option::None;
+        self
+    }
+}
+impl JobBuilder {
+    pub const FIELDS: &'static [JobBuilderFieldMeta] = &[
+        JobBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        JobBuilderFieldMeta {
+            name: "weight",
+            ty: "usize",
+            required: false,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Job> for JobBuilder<(String,)> {
+    fn from(value: Job) -> Self {
+        JobBuilder {
+            name: (value.name,),
+            weight: ::core::option::Option::Some(value.weight),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Job {
+    pub fn to_builder(&self) -> JobBuilder<(String,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> usize: ::core::clone::Clone,
+    {
+        JobBuilder {
+            name: (::core::clone::Clone::clone(&self.name),),
+            weight: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.weight),
+            ),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`JobBuilder::build()` requires field `name` to be set",
+        label = "call `.name(..)` before `.build()`"
+    )]
+    pub trait JobBuilder_name_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> JobBuilder_name_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait JobBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> JobBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> JobBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__name> JobBuilder<__name>
+    where
+        __name: JobBuilder_maybe_set<String>,
+    {
+        pub fn name_ref(&self) -> ::core::option::Option<&String> {
+            JobBuilder_maybe_set::<String>::value_ref(&self.name)
+        }
+        pub fn is_name_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.name_ref())
+        }
+        pub fn clear_name(self) -> JobBuilder<()> {
+            JobBuilder {
+                name: (),
+                weight: self.weight,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_name_set() {
+                missing.push("name");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__name> JobBuilder<__name> {
+        pub fn build(self) -> Job
+        where
+            __name: JobBuilder_name_is_set<String>,
+        {
+            let name = JobBuilder_name_is_set::value(self.name);
+            let weight = match self.weight {
+                ::core::option::Option::Some(__value) => __value,
+                ::core::option::Option::None => name.len(),
+            };
+            Job { name, weight }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct JobBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,614 @@
+#[derive(::core::fmt::Debug)]
+pub struct RequestBuilder<'a, B: Body, const N: usize>
+where
+    B: Clone,
+{
+    method: ::core::option::Option<Cow<'a, str>>,
+    body: ::core::option::Option<B>,
+    headers: ::core::option::Option<Vec<&'a str>>,
+    referer: ::core::option::Option<&'a str>,
+    marker: ::core::option::Option<PhantomData<[u8; N]>>,
+}
+impl<'a, B: Body, const N: usize> ::core::default::Default for RequestBuilder<'a, B, N>
+where
+    B: Clone,
+{
+    fn default() -> Self {
+        RequestBuilder {
+            method: ::core::option::Option::None,
+            body: ::core::option::Option::None,
+            headers: ::core::option::Option::None,
+            referer: ::core::option::Option::None,
+            marker: ::core::option::Option::None,
+        }
+    }
+}
+impl<'a, B: Body, const N: usize> Request<'a, B, N>
+where
+    B: Clone,
+{
+    pub fn builder() -> RequestBuilder<'a, B, N> {
+        <RequestBuilder<'a, B, N> as ::core::default::Default>::default()
+    }
+}
+impl<'a, B: Body, const N: usize> RequestBuilder<'a, B, N>
+where
+    B: Clone,
+{
+    pub fn method(&mut self, val: Cow<'a, str>) -> &mut Self {
+        self.method = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn body(&mut self, val: B) -> &mut Self {
+        self.body = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn headers(&mut self, val: Vec<&'a str>) -> &mut Self {
+        self.headers = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn header(&mut self, val: &'a str) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.headers.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_headers<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = &'a str>,
+    {
+        ::core::iter::Extend::extend(
+            self.headers.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn referer(&mut self, val: &'a str) -> &mut Self {
+        self.referer = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn marker(&mut self, val: PhantomData<[u8; N]>) -> &mut Self {
+        self.marker = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn method_ref(&self) -> ::core::option::Option<&Cow<'a, str>> {
+        ::core::option::Option::as_ref(&self.method)
+    }
+    pub fn is_method_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.method)
+    }
+    pub fn clear_method(&mut self) -> &mut Self {
+        self.method = ::core::option::Option::None;
+        self
+    }
+    pub fn body_ref(&self) -> ::core::option::Option<&B> {
+        ::core::option::Option::as_ref(&self.body)
+    }
+    pub fn is_body_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.body)
+    }
+    pub fn clear_body(&mut self) -> &mut Self {
+        self.body = ::core::option::Option::None;
+        self
+    }
+    pub fn headers_ref(&self) -> ::core::option::Option<&Vec<&'a str>> {
+        ::core::option::Option::as_ref(&self.headers)
+    }
+    pub fn is_headers_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.headers)
+    }
+    pub fn clear_headers(&mut self) -> &mut Self {
+        self.headers = ::core::option::Option::None;
+        self
+    }
+    pub fn referer_ref(&self) -> ::core::option::Option<&&'a str> {
+        ::core::option::Option::as_ref(&self.referer)
+    }
+    pub fn is_referer_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.referer)
+    }
+    pub fn clear_referer(&mut self) -> &mut Self {
+        self.referer = ::core::option::Option::None;
+        self
+    }
+    pub fn marker_ref(&self) -> ::core::option::Option<&PhantomData<[u8; N]>> {
+        ::core::option::Option::as_ref(&self.marker)
+    }
+    pub fn is_marker_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.marker)
+    }
+    pub fn clear_marker(&mut self) -> &mut Self {
+        self.marker = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.method.is_none() {
+            missing.push("method");
+        }
+        if self.body.is_none() {
+            missing.push("body");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [RequestBuilderFieldMeta] = &[
+        RequestBuilderFieldMeta {
+            name: "method",
+            ty: "Cow<'a, str>",
+            required: true,
+            doc: "",
+        },
+        RequestBuilderFieldMeta {
+            name: "body",
+            ty: "B",
+            required: true,
+            doc: "",
+        },
+        RequestBuilderFieldMeta {
+            name: "headers",
+            ty: "Vec<&'a str>",
+            required: false,
+            doc: "",
+        },
+        RequestBuilderFieldMeta {
+            name: "referer",
+            ty: "Option<&'a str>",
+            required: false,
+            doc: "",
+        },
+        RequestBuilderFieldMeta {
+            name: "marker",
+            ty: "PhantomData<[u8; N]>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), RequestBuilderError>
+    where
+        for<'__a> B: ::core::str::FromStr,
+        for<'__a> <B as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> PhantomData<[u8; N]>: ::core::str::FromStr,
+        for<'__a> <PhantomData<
+            [u8; N],
+        > as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "method" => {
+                ::core::result::Result::Err(RequestBuilderError::InvalidField {
+                    field: "method",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "body" => {
+                self.body = ::core::option::Option::Some(
+                    match <B as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(RequestBuilderError::InvalidField {
+                                field: "body",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "headers" => {
+                ::core::result::Result::Err(RequestBuilderError::InvalidField {
+                    field: "headers",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "referer" => {
+                ::core::result::Result::Err(RequestBuilderError::InvalidField {
+                    field: "referer",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "marker" => {
+                self.marker = ::core::option::Option::Some(
+                    match <PhantomData<
+                        [u8; N],
+                    > as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(RequestBuilderError::InvalidField {
+                                field: "marker",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    RequestBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(
+        &mut self,
+    ) -> ::core::result::Result<Request<'a, B, N>, RequestBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.method.is_none() {
+            missing.push("method");
+        }
+        if self.body.is_none() {
+            missing.push("body");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                RequestBuilderError::MissingFields(missing),
+            );
+        }
+        let method = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.method),
+        );
+        let body = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.body),
+        );
+        let headers = match ::core::clone::Clone::clone(&self.headers) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let referer = ::core::clone::Clone::clone(&self.referer);
+        let marker = match ::core::clone::Clone::clone(&self.marker) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let __built = Request {
+            method,
+            body,
+            headers,
+            referer,
+            marker,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Request<'a, B, N>, RequestBuilderError> {
+        self.build()
+    }
+}
+impl<'a, B: Body, const N: usize> ::core::convert::From<Request<'a, B, N>>
+for RequestBuilder<'a, B, N>
+where
+    B: Clone,
+{
+    fn from(value: Request<'a, B, N>) -> Self {
+        RequestBuilder {
+            method: ::core::option::Option::Some(value.method),
+            body: ::core::option::Option::Some(value.body),
+            headers: ::core::option::Option::Some(value.headers),
+            referer: value.referer,
+            marker: ::core::option::Option::Some(value.marker),
+        }
+    }
+}
+impl<'a, B: Body, const N: usize> Request<'a, B, N>
+where
+    B: Clone,
+{
+    pub fn to_builder(&self) -> RequestBuilder<'a, B, N>
+    where
+        for<'__a> Cow<'a, str>: ::core::clone::Clone,
+        for<'__a> B: ::core::clone::Clone,
+        for<'__a> Vec<&'a str>: ::core::clone::Clone,
+        for<'__a> &'a str: ::core::clone::Clone,
+        for<'__a> PhantomData<[u8; N]>: ::core::clone::Clone,
+    {
+        RequestBuilder {
+            method: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.method),
+            ),
+            body: ::core::option::Option::Some(::core::clone::Clone::clone(&self.body)),
+            headers: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.headers),
+            ),
+            referer: ::core::clone::Clone::clone(&self.referer),
+            marker: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.marker),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum RequestBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl RequestBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            RequestBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            RequestBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            RequestBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for RequestBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            RequestBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            RequestBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            RequestBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for RequestBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct RequestBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct ResponseBuilder<'a, B: Body, __status = (), __body = ()> {
+    status: __status,
+    body: __body,
+    reason: ::core::option::Option<&'a str>,
+    __marker: ::core::marker::PhantomData<fn() -> Response<'a, B>>,
+}
+impl<'a, B: Body> Response<'a, B> {
+    pub fn builder() -> ResponseBuilder<'a, B> {
+        ResponseBuilder {
+            status: (),
+            body: (),
+            reason: ::core::option::Option::None,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<'a, B: Body, __status, __body> ResponseBuilder<'a, B, __status, __body> {
+    pub fn status(self, val: u16) -> ResponseBuilder<'a, B, (u16,), __body> {
+        ResponseBuilder {
+            status: (val,),
+            body: self.body,
+            reason: self.reason,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn body(self, val: B) -> ResponseBuilder<'a, B, __status, (B,)> {
+        ResponseBuilder {
+            status: self.status,
+            body: (val,),
+            reason: self.reason,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn reason(mut self, val: &'a str) -> Self {
+        self.reason = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn reason_ref(&self) -> ::core::option::Option<&&'a str> {
+        ::core::option::Option::as_ref(&self.reason)
+    }
+    pub fn is_reason_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.reason)
+    }
+    pub fn clear_reason(mut self) -> Self {
+        self.reason = ::core::option::Option::None;
+        self
+    }
+}
+impl<'a, B: Body> ResponseBuilder<'a, B> {
+    pub const FIELDS: &'static [ResponseBuilderFieldMeta] = &[
+        ResponseBuilderFieldMeta {
+            name: "status",
+            ty: "u16",
+            required: true,
+            doc: "",
+        },
+        ResponseBuilderFieldMeta {
+            name: "body",
+            ty: "B",
+            required: true,
+            doc: "",
+        },
+        ResponseBuilderFieldMeta {
+            name: "reason",
+            ty: "Option<&'a str>",
+            required: false,
+            doc: "",
+        },
+    ];
+}
+impl<'a, B: Body> ::core::convert::From<Response<'a, B>>
+for ResponseBuilder<'a, B, (u16,), (B,)> {
+    fn from(value: Response<'a, B>) -> Self {
+        ResponseBuilder {
+            status: (value.status,),
+            body: (value.body,),
+            reason: value.reason,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl<'a, B: Body> Response<'a, B> {
+    pub fn to_builder(&self) -> ResponseBuilder<'a, B, (u16,), (B,)>
+    where
+        for<'__a> u16: ::core::clone::Clone,
+        for<'__a> B: ::core::clone::Clone,
+        for<'__a> &'a str: ::core::clone::Clone,
+    {
+        ResponseBuilder {
+            status: (::core::clone::Clone::clone(&self.status),),
+            body: (::core::clone::Clone::clone(&self.body),),
+            reason: ::core::clone::Clone::clone(&self.reason),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`ResponseBuilder::build()` requires field `status` to be set",
+        label = "call `.status(..)` before `.build()`"
+    )]
+    pub trait ResponseBuilder_status_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> ResponseBuilder_status_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`ResponseBuilder::build()` requires field `body` to be set",
+        label = "call `.body(..)` before `.build()`"
+    )]
+    pub trait ResponseBuilder_body_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> ResponseBuilder_body_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait ResponseBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> ResponseBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> ResponseBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<'a, B: Body, __status, __body> ResponseBuilder<'a, B, __status, __body>
+    where
+        __status: ResponseBuilder_maybe_set<u16>,
+        __body: ResponseBuilder_maybe_set<B>,
+    {
+        pub fn status_ref(&self) -> ::core::option::Option<&u16> {
+            ResponseBuilder_maybe_set::<u16>::value_ref(&self.status)
+        }
+        pub fn is_status_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.status_ref())
+        }
+        pub fn clear_status(self) -> ResponseBuilder<'a, B, (), __body> {
+            ResponseBuilder {
+                status: (),
+                body: self.body,
+                reason: self.reason,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn body_ref(&self) -> ::core::option::Option<&B> {
+            ResponseBuilder_maybe_set::<B>::value_ref(&self.body)
+        }
+        pub fn is_body_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.body_ref())
+        }
+        pub fn clear_body(self) -> ResponseBuilder<'a, B, __status, ()> {
+            ResponseBuilder {
+                status: self.status,
+                body: (),
+                reason: self.reason,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_status_set() {
+                missing.push("status");
+            }
+            if !self.is_body_set() {
+                missing.push("body");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<'a, B: Body, __status, __body> ResponseBuilder<'a, B, __status, __body> {
+        pub fn build(self) -> Response<'a, B>
+        where
+            __status: ResponseBuilder_status_is_set<u16>,
+            __body: ResponseBuilder_body_is_set<B>,
+        {
+            let status = ResponseBuilder_status_is_set::value(self.status);
+            let body = ResponseBuilder_body_is_set::value(self.body);
+            let reason = self.reason;
+            Response { status, body, reason }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct ResponseBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,9 @@
+::core::compile_error! {
+    "can be derived only for structs"
+}
+::core::compile_error! {
+    "can be derived only for structs with named fields"
+}
+::core::compile_error! {
+    "can be derived only for structs with named fields"
+}
@@ -0,0 +1,430 @@
+#[derive(::core::fmt::Debug)]
+pub struct PacketBuilder<'a> {
+    name: ::core::option::Option<&'a str>,
+    addr: ::core::option::Option<[u8; 4]>,
+    port_range: ::core::option::Option<(u16, u16)>,
+    on_send: ::core::option::Option<fn(usize) -> usize>,
+    first: ::core::option::Option<<Vec<u8> as IntoIterator>::Item>,
+    payload: ::core::option::Option<&'a [u8]>,
+}
+impl<'a> ::core::default::Default for PacketBuilder<'a> {
+    fn default() -> Self {
+        PacketBuilder {
+            name: ::core::option::Option::None,
+            addr: ::core::option::Option::None,
+            port_range: ::core::option::Option::None,
+            on_send: ::core::option::Option::None,
+            first: ::core::option::Option::None,
+            payload: ::core::option::Option::None,
+        }
+    }
+}
+impl<'a> Packet<'a> {
+    pub fn builder() -> PacketBuilder<'a> {
+        <PacketBuilder<'a> as ::core::default::Default>::default()
+    }
+}
+impl<'a> PacketBuilder<'a> {
+    pub fn name(&mut self, val: &'a str) -> &mut Self {
+        self.name = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn addr(&mut self, val: [u8; 4]) -> &mut Self {
+        self.addr = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn port_range(&mut self, val: (u16, u16)) -> &mut Self {
+        self.port_range = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn on_send(&mut self, val: fn(usize) -> usize) -> &mut Self {
+        self.on_send = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn first(&mut self, val: <Vec<u8> as IntoIterator>::Item) -> &mut Self {
+        self.first = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn payload(&mut self, val: &'a [u8]) -> &mut Self {
+        self.payload = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn name_ref(&self) -> ::core::option::Option<&&'a str> {
+        ::core::option::Option::as_ref(&self.name)
+    }
+    pub fn is_name_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.name)
+    }
+    pub fn clear_name(&mut self) -> &mut Self {
+        self.name = ::core::option::Option::None;
+        self
+    }
+    pub fn addr_ref(&self) -> ::core::option::Option<&[u8; 4]> {
+        ::core::option::Option::as_ref(&self.addr)
+    }
+    pub fn is_addr_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.addr)
+    }
+    pub fn clear_addr(&mut self) -> &mut Self {
+        self.addr = ::core::option::Option::None;
+        self
+    }
+    pub fn port_range_ref(&self) -> ::core::option::Option<&(u16, u16)> {
+        ::core::option::Option::as_ref(&self.port_range)
+    }
+    pub fn is_port_range_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.port_range)
+    }
+    pub fn clear_port_range(&mut self) -> &mut Self {
+        self.port_range = ::core::option::Option::None;
+        self
+    }
+    pub fn on_send_ref(&self) -> ::core::option::Option<&fn(usize) -> usize> {
+        ::core::option::Option::as_ref(&self.on_send)
+    }
+    pub fn is_on_send_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.on_send)
+    }
+    pub fn clear_on_send(&mut self) -> &mut Self {
+        self.on_send = ::core::option::Option::None;
+        self
+    }
+    pub fn first_ref(&self) -> ::core::option::Option<&<Vec<u8> as IntoIterator>::Item> {
+        ::core::option::Option::as_ref(&self.first)
+    }
+    pub fn is_first_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.first)
+    }
+    pub fn clear_first(&mut self) -> &mut Self {
+        self.first = ::core::option::Option::None;
+        self
+    }
+    pub fn payload_ref(&self) -> ::core::option::Option<&&'a [u8]> {
+        ::core::option::Option::as_ref(&self.payload)
+    }
+    pub fn is_payload_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.payload)
+    }
+    pub fn clear_payload(&mut self) -> &mut Self {
+        self.payload = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        if self.addr.is_none() {
+            missing.push("addr");
+        }
+        if self.port_range.is_none() {
+            missing.push("port_range");
+        }
+        if self.on_send.is_none() {
+            missing.push("on_send");
+        }
+        if self.first.is_none() {
+            missing.push("first");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [PacketBuilderFieldMeta] = &[
+        PacketBuilderFieldMeta {
+            name: "name",
+            ty: "&'a str",
+            required: true,
+            doc: "",
+        },
+        PacketBuilderFieldMeta {
+            name: "addr",
+            ty: "[u8; 4]",
+            required: true,
+            doc: "",
+        },
+        PacketBuilderFieldMeta {
+            name: "port_range",
+            ty: "(u16, u16)",
+            required: true,
+            doc: "",
+        },
+        PacketBuilderFieldMeta {
+            name: "on_send",
+            ty: "fn (usize) -> usize",
+            required: true,
+            doc: "",
+        },
+        PacketBuilderFieldMeta {
+            name: "first",
+            ty: "<Vec<u8> as IntoIterator>::Item",
+            required: true,
+            doc: "",
+        },
+        PacketBuilderFieldMeta {
+            name: "payload",
+            ty: "Option<&'a [u8]>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), PacketBuilderError>
+    where
+        for<'__a> [u8; 4]: ::core::str::FromStr,
+        for<'__a> <[u8; 4] as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> (u16, u16): ::core::str::FromStr,
+        for<'__a> <(u16, u16) as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> fn(usize) -> usize: ::core::str::FromStr,
+        for<'__a> <fn(
+            usize,
+        ) -> usize as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> <Vec<u8> as IntoIterator>::Item: ::core::str::FromStr,
+        for<'__a> <<Vec<
+            u8,
+        > as IntoIterator>::Item as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "name" => {
+                ::core::result::Result::Err(PacketBuilderError::InvalidField {
+                    field: "name",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "addr" => {
+                self.addr = ::core::option::Option::Some(
+                    match <[u8; 4] as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(PacketBuilderError::InvalidField {
+                                field: "addr",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "port_range" => {
+                self.port_range = ::core::option::Option::Some(
+                    match <(u16, u16) as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(PacketBuilderError::InvalidField {
+                                field: "port_range",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "on_send" => {
+                self.on_send = ::core::option::Option::Some(
+                    match <fn(usize) -> usize as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(PacketBuilderError::InvalidField {
+                                field: "on_send",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "first" => {
+                self.first = ::core::option::Option::Some(
+                    match <<Vec<
+                        u8,
+                    > as IntoIterator>::Item as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(PacketBuilderError::InvalidField {
+                                field: "first",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "payload" => {
+                ::core::result::Result::Err(PacketBuilderError::InvalidField {
+                    field: "payload",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    PacketBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Packet<'a>, PacketBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        if self.addr.is_none() {
+            missing.push("addr");
+        }
+        if self.port_range.is_none() {
+            missing.push("port_range");
+        }
+        if self.on_send.is_none() {
+            missing.push("on_send");
+        }
+        if self.first.is_none() {
+            missing.push("first");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                PacketBuilderError::MissingFields(missing),
+            );
+        }
+        let name = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.name),
+        );
+        let addr = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.addr),
+        );
+        let port_range = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.port_range),
+        );
+        let on_send = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.on_send),
+        );
+        let first = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.first),
+        );
+        let payload = ::core::clone::Clone::clone(&self.payload);
+        let __built = Packet {
+            name,
+            addr,
+            port_range,
+            on_send,
+            first,
+            payload,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Packet<'a>, PacketBuilderError> {
+        self.build()
+    }
+}
+impl<'a> ::core::convert::From<Packet<'a>> for PacketBuilder<'a> {
+    fn from(value: Packet<'a>) -> Self {
+        PacketBuilder {
+            name: ::core::option::Option::Some(value.name),
+            addr: ::core::option::Option::Some(value.addr),
+            port_range: ::core::option::Option::Some(value.port_range),
+            on_send: ::core::option::Option::Some(value.on_send),
+            first: ::core::option::Option::Some(value.first),
+            payload: value.payload,
+        }
+    }
+}
+impl<'a> Packet<'a> {
+    pub fn to_builder(&self) -> PacketBuilder<'a>
+    where
+        for<'__a> &'a str: ::core::clone::Clone,
+        for<'__a> [u8; 4]: ::core::clone::Clone,
+        for<'__a> (u16, u16): ::core::clone::Clone,
+        for<'__a> fn(usize) -> usize: ::core::clone::Clone,
+        for<'__a> <Vec<u8> as IntoIterator>::Item: ::core::clone::Clone,
+        for<'__a> &'a [u8]: ::core::clone::Clone,
+    {
+        PacketBuilder {
+            name: ::core::option::Option::Some(::core::clone::Clone::clone(&self.name)),
+            addr: ::core::option::Option::Some(::core::clone::Clone::clone(&self.addr)),
+            port_range: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.port_range),
+            ),
+            on_send: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.on_send),
+            ),
+            first: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.first),
+            ),
+            payload: ::core::clone::Clone::clone(&self.payload),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum PacketBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl PacketBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            PacketBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            PacketBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            PacketBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for PacketBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            PacketBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            PacketBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            PacketBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for PacketBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct PacketBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
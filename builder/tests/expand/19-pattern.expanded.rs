@@ -0,0 +1,793 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    args: ::core::option::Option<Vec<String>>,
+    handle: ::core::option::Option<Handle>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            args: ::core::option::Option::None,
+            handle: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(mut self, val: String) -> Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn args(mut self, val: Vec<String>) -> Self {
+        self.args = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn arg(mut self, val: String) -> Self {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_args<__I>(mut self, iter: __I) -> Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn handle(mut self, val: Handle) -> Self {
+        self.handle = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(mut self) -> Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.args)
+    }
+    pub fn is_args_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.args)
+    }
+    pub fn clear_args(mut self) -> Self {
+        self.args = ::core::option::Option::None;
+        self
+    }
+    pub fn handle_ref(&self) -> ::core::option::Option<&Handle> {
+        ::core::option::Option::as_ref(&self.handle)
+    }
+    pub fn is_handle_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.handle)
+    }
+    pub fn clear_handle(mut self) -> Self {
+        self.handle = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if self.handle.is_none() {
+            missing.push("handle");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "handle",
+            ty: "Handle",
+            required: true,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> Handle: ::core::str::FromStr,
+        for<'__a> <Handle as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "args" => {
+                ::core::iter::Extend::extend(
+                    self.args.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "args",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "handle" => {
+                self.handle = ::core::option::Option::Some(
+                    match <Handle as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "handle",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if self.handle.is_none() {
+            missing.push("handle");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(self.executable);
+        let args = match self.args {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let handle = ::core::option::Option::unwrap(self.handle);
+        let __built = Command {
+            executable,
+            args,
+            handle,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(self) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            args: ::core::option::Option::Some(value.args),
+            handle: ::core::option::Option::Some(value.handle),
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> Handle: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            args: ::core::option::Option::Some(::core::clone::Clone::clone(&self.args)),
+            handle: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.handle),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug, ::core::clone::Clone)]
+pub struct JobBuilder {
+    name: ::core::option::Option<String>,
+    steps: ::core::option::Option<Vec<String>>,
+    retries: ::core::option::Option<u32>,
+}
+impl ::core::default::Default for JobBuilder {
+    fn default() -> Self {
+        JobBuilder {
+            name: ::core::option::Option::None,
+            steps: ::core::option::Option::None,
+            retries: ::core::option::Option::None,
+        }
+    }
+}
+impl Job {
+    pub fn builder() -> JobBuilder {
+        <JobBuilder as ::core::default::Default>::default()
+    }
+}
+impl JobBuilder {
+    pub fn name(&self, val: String) -> Self {
+        let mut __builder = ::core::clone::Clone::clone(self);
+        __builder.name = ::core::option::Option::Some(val);
+        __builder
+    }
+    pub fn steps(&self, val: Vec<String>) -> Self {
+        let mut __builder = ::core::clone::Clone::clone(self);
+        __builder.steps = ::core::option::Option::Some(val);
+        __builder
+    }
+    pub fn step(&self, val: String) -> Self {
+        let mut __builder = ::core::clone::Clone::clone(self);
+        ::core::iter::Extend::extend(
+            __builder.steps.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        __builder
+    }
+    pub fn extend_steps<__I>(&self, iter: __I) -> Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        let mut __builder = ::core::clone::Clone::clone(self);
+        ::core::iter::Extend::extend(
+            __builder.steps.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        __builder
+    }
+    pub fn retries(&self, val: u32) -> Self {
+        let mut __builder = ::core::clone::Clone::clone(self);
+        __builder.retries = ::core::option::Option::Some(val);
+        __builder
+    }
+    pub fn name_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.name)
+    }
+    pub fn is_name_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.name)
+    }
+    pub fn clear_name(&self) -> Self {
+        let mut __builder = ::core::clone::Clone::clone(self);
+        __builder.name = ::core::option::Option::None;
+        __builder
+    }
+    pub fn steps_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.steps)
+    }
+    pub fn is_steps_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.steps)
+    }
+    pub fn clear_steps(&self) -> Self {
+        let mut __builder = ::core::clone::Clone::clone(self);
+        __builder.steps = ::core::option::Option::None;
+        __builder
+    }
+    pub fn retries_ref(&self) -> ::core::option::Option<&u32> {
+        ::core::option::Option::as_ref(&self.retries)
+    }
+    pub fn is_retries_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.retries)
+    }
+    pub fn clear_retries(&self) -> Self {
+        let mut __builder = ::core::clone::Clone::clone(self);
+        __builder.retries = ::core::option::Option::None;
+        __builder
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [JobBuilderFieldMeta] = &[
+        JobBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        JobBuilderFieldMeta {
+            name: "steps",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+        JobBuilderFieldMeta {
+            name: "retries",
+            ty: "Option<u32>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), JobBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u32: ::core::str::FromStr,
+        for<'__a> <u32 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "name" => {
+                self.name = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(JobBuilderError::InvalidField {
+                                field: "name",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "steps" => {
+                ::core::iter::Extend::extend(
+                    self.steps.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(JobBuilderError::InvalidField {
+                                    field: "steps",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "retries" => {
+                self.retries = ::core::option::Option::Some(
+                    match <u32 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(JobBuilderError::InvalidField {
+                                field: "retries",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    JobBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&self) -> ::core::result::Result<Job, JobBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(JobBuilderError::MissingFields(missing));
+        }
+        let name = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.name),
+        );
+        let steps = match ::core::clone::Clone::clone(&self.steps) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let retries = ::core::clone::Clone::clone(&self.retries);
+        let __built = Job { name, steps, retries };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(&self) -> ::core::result::Result<Job, JobBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Job> for JobBuilder {
+    fn from(value: Job) -> Self {
+        JobBuilder {
+            name: ::core::option::Option::Some(value.name),
+            steps: ::core::option::Option::Some(value.steps),
+            retries: value.retries,
+        }
+    }
+}
+impl Job {
+    pub fn to_builder(&self) -> JobBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> u32: ::core::clone::Clone,
+    {
+        JobBuilder {
+            name: ::core::option::Option::Some(::core::clone::Clone::clone(&self.name)),
+            steps: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.steps),
+            ),
+            retries: ::core::clone::Clone::clone(&self.retries),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum JobBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl JobBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            JobBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            JobBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            JobBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for JobBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            JobBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            JobBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            JobBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for JobBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct JobBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct TaskBuilder {
+    name: ::core::option::Option<String>,
+}
+impl ::core::default::Default for TaskBuilder {
+    fn default() -> Self {
+        TaskBuilder {
+            name: ::core::option::Option::None,
+        }
+    }
+}
+impl Task {
+    pub fn builder() -> TaskBuilder {
+        <TaskBuilder as ::core::default::Default>::default()
+    }
+}
+impl TaskBuilder {
+    pub fn name(&mut self, val: String) -> &mut Self {
+        self.name = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn name_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.name)
+    }
+    pub fn is_name_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.name)
+    }
+    pub fn clear_name(&mut self) -> &mut Self {
+        self.name = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [TaskBuilderFieldMeta] = &[
+        TaskBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), TaskBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "name" => {
+                self.name = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(TaskBuilderError::InvalidField {
+                                field: "name",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    TaskBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Task, TaskBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(TaskBuilderError::MissingFields(missing));
+        }
+        let name = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.name),
+        );
+        let __built = Task { name };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(&mut self) -> ::core::result::Result<Task, TaskBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Task> for TaskBuilder {
+    fn from(value: Task) -> Self {
+        TaskBuilder {
+            name: ::core::option::Option::Some(value.name),
+        }
+    }
+}
+impl Task {
+    pub fn to_builder(&self) -> TaskBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+    {
+        TaskBuilder {
+            name: ::core::option::Option::Some(::core::clone::Clone::clone(&self.name)),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum TaskBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl TaskBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            TaskBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            TaskBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            TaskBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for TaskBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            TaskBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            TaskBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            TaskBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for TaskBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct TaskBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,6 @@
+::core::compile_error! {
+    "expected `mutable`, `owned` or `immutable`"
+}
+::core::compile_error! {
+    "typestate builders always use the `owned` pattern"
+}
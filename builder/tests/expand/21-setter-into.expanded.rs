@@ -0,0 +1,815 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    args: ::core::option::Option<Vec<String>>,
+    env: ::core::option::Option<HashMap<String, String>>,
+    current_dir: ::core::option::Option<String>,
+    user: ::core::option::Option<String>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            args: ::core::option::Option::None,
+            env: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+            user: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: impl ::core::convert::Into<String>) -> &mut Self {
+        self.executable = ::core::option::Option::Some(::core::convert::Into::into(val));
+        self
+    }
+    pub fn args(&mut self, val: impl ::core::convert::Into<Vec<String>>) -> &mut Self {
+        self.args = ::core::option::Option::Some(::core::convert::Into::into(val));
+        self
+    }
+    pub fn arg(&mut self, val: impl ::core::convert::Into<String>) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(::core::convert::Into::into(val)),
+        );
+        self
+    }
+    pub fn extend_args<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn env(
+        &mut self,
+        key: impl ::core::convert::Into<String>,
+        value: impl ::core::convert::Into<String>,
+    ) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.env.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once((
+                ::core::convert::Into::into(key),
+                ::core::convert::Into::into(value),
+            )),
+        );
+        self
+    }
+    pub fn extend_env<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = (String, String)>,
+    {
+        ::core::iter::Extend::extend(
+            self.env.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn current_dir(&mut self, val: ::core::option::Option<String>) -> &mut Self {
+        self.current_dir = val;
+        self
+    }
+    pub fn user(
+        &mut self,
+        val: impl ::core::convert::Into<::core::option::Option<String>>,
+    ) -> &mut Self {
+        self.user = ::core::convert::Into::into(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.args)
+    }
+    pub fn is_args_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.args)
+    }
+    pub fn clear_args(&mut self) -> &mut Self {
+        self.args = ::core::option::Option::None;
+        self
+    }
+    pub fn env_ref(&self) -> ::core::option::Option<&HashMap<String, String>> {
+        ::core::option::Option::as_ref(&self.env)
+    }
+    pub fn is_env_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.env)
+    }
+    pub fn clear_env(&mut self) -> &mut Self {
+        self.env = ::core::option::Option::None;
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(&mut self) -> &mut Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+    pub fn user_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.user)
+    }
+    pub fn is_user_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.user)
+    }
+    pub fn clear_user(&mut self) -> &mut Self {
+        self.user = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "env",
+            ty: "HashMap<String, String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "user",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "args" => {
+                ::core::iter::Extend::extend(
+                    self.args.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "args",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "env" => {
+                ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "env",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "current_dir" => {
+                self.current_dir = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "current_dir",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "user" => {
+                self.user = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "user",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let args = match ::core::clone::Clone::clone(&self.args) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let env = match ::core::clone::Clone::clone(&self.env) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let current_dir = ::core::clone::Clone::clone(&self.current_dir);
+        let user = ::core::clone::Clone::clone(&self.user);
+        let __built = Command {
+            executable,
+            args,
+            env,
+            current_dir,
+            user,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            args: ::core::option::Option::Some(value.args),
+            env: ::core::option::Option::Some(value.env),
+            current_dir: value.current_dir,
+            user: value.user,
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> HashMap<String, String>: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            args: ::core::option::Option::Some(::core::clone::Clone::clone(&self.args)),
+            env: ::core::option::Option::Some(::core::clone::Clone::clone(&self.env)),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+            user: ::core::clone::Clone::clone(&self.user),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct JobBuilder {
+    name: ::core::option::Option<String>,
+    timeout: ::core::option::Option<u64>,
+    owner: ::core::option::Option<String>,
+}
+impl ::core::default::Default for JobBuilder {
+    fn default() -> Self {
+        JobBuilder {
+            name: ::core::option::Option::None,
+            timeout: ::core::option::Option::None,
+            owner: ::core::option::Option::None,
+        }
+    }
+}
+impl Job {
+    pub fn builder() -> JobBuilder {
+        <JobBuilder as ::core::default::Default>::default()
+    }
+}
+impl JobBuilder {
+    pub fn name(&mut self, val: impl ::core::convert::Into<String>) -> &mut Self {
+        self.name = ::core::option::Option::Some(::core::convert::Into::into(val));
+        self
+    }
+    pub fn timeout(
+        &mut self,
+        val: impl ::core::convert::Into<::core::option::Option<u64>>,
+    ) -> &mut Self {
+        self.timeout = ::core::convert::Into::into(val);
+        self
+    }
+    pub fn owner(&mut self, val: String) -> &mut Self {
+        self.owner = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn name_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.name)
+    }
+    pub fn is_name_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.name)
+    }
+    pub fn clear_name(&mut self) -> &mut Self {
+        self.name = ::core::option::Option::None;
+        self
+    }
+    pub fn timeout_ref(&self) -> ::core::option::Option<&u64> {
+        ::core::option::Option::as_ref(&self.timeout)
+    }
+    pub fn is_timeout_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.timeout)
+    }
+    pub fn clear_timeout(&mut self) -> &mut Self {
+        self.timeout = ::core::option::Option::None;
+        self
+    }
+    pub fn owner_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.owner)
+    }
+    pub fn is_owner_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.owner)
+    }
+    pub fn clear_owner(&mut self) -> &mut Self {
+        self.owner = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [JobBuilderFieldMeta] = &[
+        JobBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        JobBuilderFieldMeta {
+            name: "timeout",
+            ty: "Option<u64>",
+            required: false,
+            doc: "",
+        },
+        JobBuilderFieldMeta {
+            name: "owner",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), JobBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u64: ::core::str::FromStr,
+        for<'__a> <u64 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "name" => {
+                self.name = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(JobBuilderError::InvalidField {
+                                field: "name",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "timeout" => {
+                self.timeout = ::core::option::Option::Some(
+                    match <u64 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(JobBuilderError::InvalidField {
+                                field: "timeout",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "owner" => {
+                self.owner = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(JobBuilderError::InvalidField {
+                                field: "owner",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    JobBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Job, JobBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(JobBuilderError::MissingFields(missing));
+        }
+        let name = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.name),
+        );
+        let timeout = ::core::clone::Clone::clone(&self.timeout);
+        let owner = ::core::clone::Clone::clone(&self.owner);
+        let __built = Job { name, timeout, owner };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(&mut self) -> ::core::result::Result<Job, JobBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Job> for JobBuilder {
+    fn from(value: Job) -> Self {
+        JobBuilder {
+            name: ::core::option::Option::Some(value.name),
+            timeout: value.timeout,
+            owner: value.owner,
+        }
+    }
+}
+impl Job {
+    pub fn to_builder(&self) -> JobBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> u64: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+    {
+        JobBuilder {
+            name: ::core::option::Option::Some(::core::clone::Clone::clone(&self.name)),
+            timeout: ::core::clone::Clone::clone(&self.timeout),
+            owner: ::core::clone::Clone::clone(&self.owner),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum JobBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl JobBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            JobBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            JobBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            JobBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for JobBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            JobBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            JobBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            JobBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for JobBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct JobBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct TaskBuilder<__name = ()> {
+    name: __name,
+    __marker: ::core::marker::PhantomData<fn() -> Task>,
+}
+impl Task {
+    pub fn builder() -> TaskBuilder {
+        TaskBuilder {
+            name: (),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__name> TaskBuilder<__name> {
+    pub fn name(
+        self,
+        val: impl ::core::convert::Into<String>,
+    ) -> TaskBuilder<(String,)> {
+        TaskBuilder {
+            name: (::core::convert::Into::into(val),),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl TaskBuilder {
+    pub const FIELDS: &'static [TaskBuilderFieldMeta] = &[
+        TaskBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Task> for TaskBuilder<(String,)> {
+    fn from(value: Task) -> Self {
+        TaskBuilder {
+            name: (value.name,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Task {
+    pub fn to_builder(&self) -> TaskBuilder<(String,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+    {
+        TaskBuilder {
+            name: (::core::clone::Clone::clone(&self.name),),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`TaskBuilder::build()` requires field `name` to be set",
+        label = "call `.name(..)` before `.build()`"
+    )]
+    pub trait TaskBuilder_name_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> TaskBuilder_name_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait TaskBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> TaskBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> TaskBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__name> TaskBuilder<__name>
+    where
+        __name: TaskBuilder_maybe_set<String>,
+    {
+        pub fn name_ref(&self) -> ::core::option::Option<&String> {
+            TaskBuilder_maybe_set::<String>::value_ref(&self.name)
+        }
+        pub fn is_name_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.name_ref())
+        }
+        pub fn clear_name(self) -> TaskBuilder<()> {
+            TaskBuilder {
+                name: (),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_name_set() {
+                missing.push("name");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__name> TaskBuilder<__name> {
+        pub fn build(self) -> Task
+        where
+            __name: TaskBuilder_name_is_set<String>,
+        {
+            let name = TaskBuilder_name_is_set::value(self.name);
+            Task { name }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct TaskBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,563 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    args: ::core::option::Option<Vec<String>>,
+    port: ::core::option::Option<u16>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            args: ::core::option::Option::None,
+            port: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn args(&mut self, val: Vec<String>) -> &mut Self {
+        self.args = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn arg(&mut self, val: String) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_args<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn port(&mut self, val: u16) -> &mut Self {
+        self.port = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.args)
+    }
+    pub fn is_args_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.args)
+    }
+    pub fn clear_args(&mut self) -> &mut Self {
+        self.args = ::core::option::Option::None;
+        self
+    }
+    pub fn port_ref(&self) -> ::core::option::Option<&u16> {
+        ::core::option::Option::as_ref(&self.port)
+    }
+    pub fn is_port_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.port)
+    }
+    pub fn clear_port(&mut self) -> &mut Self {
+        self.port = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "port",
+            ty: "u16",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u16: ::core::str::FromStr,
+        for<'__a> <u16 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "args" => {
+                ::core::iter::Extend::extend(
+                    self.args.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "args",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "port" => {
+                self.port = ::core::option::Option::Some(
+                    match <u16 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "port",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let args = match ::core::clone::Clone::clone(&self.args) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let port = match ::core::clone::Clone::clone(&self.port) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => 8080,
+        };
+        if let ::core::result::Result::Err(err) = not_empty(&executable) {
+            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                field: "executable",
+                message: ::std::string::ToString::to_string(&err),
+            });
+        }
+        if let ::core::result::Result::Err(err) = checks::non_zero(&port) {
+            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                field: "port",
+                message: ::std::string::ToString::to_string(&err),
+            });
+        }
+        let __built = Command { executable, args, port };
+        if let ::core::result::Result::Err(err) = checks::shell_args(&__built) {
+            return ::core::result::Result::Err(
+                CommandBuilderError::Invalid(::std::string::ToString::to_string(&err)),
+            );
+        }
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            args: ::core::option::Option::Some(value.args),
+            port: ::core::option::Option::Some(value.port),
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> u16: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            args: ::core::option::Option::Some(::core::clone::Clone::clone(&self.args)),
+            port: ::core::option::Option::Some(::core::clone::Clone::clone(&self.port)),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    Invalid(::std::string::String),
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::Invalid(message) => {
+                ::std::vec![(path(""), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::Invalid(message) => {
+                ::core::write!(f, "validation failed: {}", message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct ServerBuilder<__host = (), __port = ()> {
+    host: __host,
+    port: __port,
+    __marker: ::core::marker::PhantomData<fn() -> Server>,
+}
+impl Server {
+    pub fn builder() -> ServerBuilder {
+        ServerBuilder {
+            host: (),
+            port: (),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__host, __port> ServerBuilder<__host, __port> {
+    pub fn host(self, val: String) -> ServerBuilder<(String,), __port> {
+        ServerBuilder {
+            host: (val,),
+            port: self.port,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn port(self, val: u16) -> ServerBuilder<__host, (u16,)> {
+        ServerBuilder {
+            host: self.host,
+            port: (val,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl ServerBuilder {
+    pub const FIELDS: &'static [ServerBuilderFieldMeta] = &[
+        ServerBuilderFieldMeta {
+            name: "host",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        ServerBuilderFieldMeta {
+            name: "port",
+            ty: "u16",
+            required: true,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Server> for ServerBuilder<(String,), (u16,)> {
+    fn from(value: Server) -> Self {
+        ServerBuilder {
+            host: (value.host,),
+            port: (value.port,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Server {
+    pub fn to_builder(&self) -> ServerBuilder<(String,), (u16,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> u16: ::core::clone::Clone,
+    {
+        ServerBuilder {
+            host: (::core::clone::Clone::clone(&self.host),),
+            port: (::core::clone::Clone::clone(&self.port),),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`ServerBuilder::build()` requires field `host` to be set",
+        label = "call `.host(..)` before `.build()`"
+    )]
+    pub trait ServerBuilder_host_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> ServerBuilder_host_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`ServerBuilder::build()` requires field `port` to be set",
+        label = "call `.port(..)` before `.build()`"
+    )]
+    pub trait ServerBuilder_port_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> ServerBuilder_port_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait ServerBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> ServerBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> ServerBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__host, __port> ServerBuilder<__host, __port>
+    where
+        __host: ServerBuilder_maybe_set<String>,
+        __port: ServerBuilder_maybe_set<u16>,
+    {
+        pub fn host_ref(&self) -> ::core::option::Option<&String> {
+            ServerBuilder_maybe_set::<String>::value_ref(&self.host)
+        }
+        pub fn is_host_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.host_ref())
+        }
+        pub fn clear_host(self) -> ServerBuilder<(), __port> {
+            ServerBuilder {
+                host: (),
+                port: self.port,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn port_ref(&self) -> ::core::option::Option<&u16> {
+            ServerBuilder_maybe_set::<u16>::value_ref(&self.port)
+        }
+        pub fn is_port_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.port_ref())
+        }
+        pub fn clear_port(self) -> ServerBuilder<__host, ()> {
+            ServerBuilder {
+                host: self.host,
+                port: (),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_host_set() {
+                missing.push("host");
+            }
+            if !self.is_port_set() {
+                missing.push("port");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__host, __port> ServerBuilder<__host, __port> {
+        pub fn build(self) -> ::core::result::Result<Server, ServerBuilderError>
+        where
+            __host: ServerBuilder_host_is_set<String>,
+            __port: ServerBuilder_port_is_set<u16>,
+        {
+            let host = ServerBuilder_host_is_set::value(self.host);
+            let port = ServerBuilder_port_is_set::value(self.port);
+            if let ::core::result::Result::Err(err) = not_empty(&host) {
+                return ::core::result::Result::Err(ServerBuilderError::InvalidField {
+                    field: "host",
+                    message: ::std::string::ToString::to_string(&err),
+                });
+            }
+            if let ::core::result::Result::Err(err) = checks::non_zero(&port) {
+                return ::core::result::Result::Err(ServerBuilderError::InvalidField {
+                    field: "port",
+                    message: ::std::string::ToString::to_string(&err),
+                });
+            }
+            let __built = Server { host, port };
+            ::core::result::Result::Ok(__built)
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum ServerBuilderError {
+    InvalidField { field: &'static str, message: ::std::string::String },
+}
+impl ServerBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            ServerBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for ServerBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ServerBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+        }
+    }
+}
+impl ::std::error::Error for ServerBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct ServerBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,706 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    args: ::core::option::Option<Vec<String>>,
+    current_dir: ::core::option::Option<String>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            args: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn args(&mut self, val: Vec<String>) -> &mut Self {
+        self.args = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn arg(&mut self, val: String) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_args<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn current_dir(&mut self, val: String) -> &mut Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.args)
+    }
+    pub fn is_args_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.args)
+    }
+    pub fn clear_args(&mut self) -> &mut Self {
+        self.args = ::core::option::Option::None;
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(&mut self) -> &mut Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "args" => {
+                ::core::iter::Extend::extend(
+                    self.args.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "args",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "current_dir" => {
+                self.current_dir = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "current_dir",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let args = match ::core::clone::Clone::clone(&self.args) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let current_dir = ::core::clone::Clone::clone(&self.current_dir);
+        let __built = Command {
+            executable,
+            args,
+            current_dir,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            args: ::core::option::Option::Some(value.args),
+            current_dir: value.current_dir,
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            args: ::core::option::Option::Some(::core::clone::Clone::clone(&self.args)),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct ProcessBuilder<T> {
+    command: ::core::option::Option<T>,
+    handle: ::core::option::Option<Handle>,
+}
+impl<T> ::core::default::Default for ProcessBuilder<T> {
+    fn default() -> Self {
+        ProcessBuilder {
+            command: ::core::option::Option::None,
+            handle: ::core::option::Option::None,
+        }
+    }
+}
+impl<T> Process<T> {
+    pub fn builder() -> ProcessBuilder<T> {
+        <ProcessBuilder<T> as ::core::default::Default>::default()
+    }
+}
+impl<T> ProcessBuilder<T> {
+    pub fn command(mut self, val: T) -> Self {
+        self.command = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn handle(mut self, val: Handle) -> Self {
+        self.handle = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn command_ref(&self) -> ::core::option::Option<&T> {
+        ::core::option::Option::as_ref(&self.command)
+    }
+    pub fn is_command_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.command)
+    }
+    pub fn clear_command(mut self) -> Self {
+        self.command = ::core::option::Option::None;
+        self
+    }
+    pub fn handle_ref(&self) -> ::core::option::Option<&Handle> {
+        ::core::option::Option::as_ref(&self.handle)
+    }
+    pub fn is_handle_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.handle)
+    }
+    pub fn clear_handle(mut self) -> Self {
+        self.handle = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.command.is_none() {
+            missing.push("command");
+        }
+        if self.handle.is_none() {
+            missing.push("handle");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [ProcessBuilderFieldMeta] = &[
+        ProcessBuilderFieldMeta {
+            name: "command",
+            ty: "T",
+            required: true,
+            doc: "",
+        },
+        ProcessBuilderFieldMeta {
+            name: "handle",
+            ty: "Handle",
+            required: true,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), ProcessBuilderError>
+    where
+        for<'__a> T: ::core::str::FromStr,
+        for<'__a> <T as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> Handle: ::core::str::FromStr,
+        for<'__a> <Handle as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "command" => {
+                self.command = ::core::option::Option::Some(
+                    match <T as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(ProcessBuilderError::InvalidField {
+                                field: "command",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "handle" => {
+                self.handle = ::core::option::Option::Some(
+                    match <Handle as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(ProcessBuilderError::InvalidField {
+                                field: "handle",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    ProcessBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(self) -> ::core::result::Result<Process<T>, ProcessBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.command.is_none() {
+            missing.push("command");
+        }
+        if self.handle.is_none() {
+            missing.push("handle");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                ProcessBuilderError::MissingFields(missing),
+            );
+        }
+        let command = ::core::option::Option::unwrap(self.command);
+        let handle = ::core::option::Option::unwrap(self.handle);
+        let __built = Process { command, handle };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(self) -> ::core::result::Result<Process<T>, ProcessBuilderError> {
+        self.build()
+    }
+}
+impl<T> ::core::convert::From<Process<T>> for ProcessBuilder<T> {
+    fn from(value: Process<T>) -> Self {
+        ProcessBuilder {
+            command: ::core::option::Option::Some(value.command),
+            handle: ::core::option::Option::Some(value.handle),
+        }
+    }
+}
+impl<T> Process<T> {
+    pub fn to_builder(&self) -> ProcessBuilder<T>
+    where
+        for<'__a> T: ::core::clone::Clone,
+        for<'__a> Handle: ::core::clone::Clone,
+    {
+        ProcessBuilder {
+            command: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.command),
+            ),
+            handle: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.handle),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum ProcessBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl ProcessBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            ProcessBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            ProcessBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            ProcessBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for ProcessBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ProcessBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            ProcessBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            ProcessBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for ProcessBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct ProcessBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct ServerBuilder<__host = ()> {
+    host: __host,
+    port: ::core::option::Option<u16>,
+    __marker: ::core::marker::PhantomData<fn() -> Server>,
+}
+impl Server {
+    pub fn builder() -> ServerBuilder {
+        ServerBuilder {
+            host: (),
+            port: ::core::option::Option::None,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__host> ServerBuilder<__host> {
+    pub fn host(self, val: String) -> ServerBuilder<(String,)> {
+        ServerBuilder {
+            host: (val,),
+            port: self.port,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn port(mut self, val: u16) -> Self {
+        self.port = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn port_ref(&self) -> ::core::option::Option<&u16> {
+        ::core::option::Option::as_ref(&self.port)
+    }
+    pub fn is_port_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.port)
+    }
+    pub fn clear_port(mut self) -> Self {
+        self.port = ::core::option::Option::None;
+        self
+    }
+}
+impl ServerBuilder {
+    pub const FIELDS: &'static [ServerBuilderFieldMeta] = &[
+        ServerBuilderFieldMeta {
+            name: "host",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        ServerBuilderFieldMeta {
+            name: "port",
+            ty: "u16",
+            required: false,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Server> for ServerBuilder<(String,)> {
+    fn from(value: Server) -> Self {
+        ServerBuilder {
+            host: (value.host,),
+            port: ::core::option::Option::Some(value.port),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Server {
+    pub fn to_builder(&self) -> ServerBuilder<(String,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> u16: ::core::clone::Clone,
+    {
+        ServerBuilder {
+            host: (::core::clone::Clone::clone(&self.host),),
+            port: ::core::option::Option::Some(::core::clone::Clone::clone(&self.port)),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`ServerBuilder::build()` requires field `host` to be set",
+        label = "call `.host(..)` before `.build()`"
+    )]
+    pub trait ServerBuilder_host_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> ServerBuilder_host_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait ServerBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> ServerBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> ServerBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__host> ServerBuilder<__host>
+    where
+        __host: ServerBuilder_maybe_set<String>,
+    {
+        pub fn host_ref(&self) -> ::core::option::Option<&String> {
+            ServerBuilder_maybe_set::<String>::value_ref(&self.host)
+        }
+        pub fn is_host_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.host_ref())
+        }
+        pub fn clear_host(self) -> ServerBuilder<()> {
+            ServerBuilder {
+                host: (),
+                port: self.port,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_host_set() {
+                missing.push("host");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__host> ServerBuilder<__host> {
+        pub fn build(self) -> Server
+        where
+            __host: ServerBuilder_host_is_set<String>,
+        {
+            let host = ServerBuilder_host_is_set::value(self.host);
+            let port = match self.port {
+                ::core::option::Option::Some(__value) => __value,
+                ::core::option::Option::None => 80,
+            };
+            Server { host, port }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct ServerBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
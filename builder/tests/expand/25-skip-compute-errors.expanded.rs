@@ -0,0 +1,9 @@
+::core::compile_error! {
+    "`skip` and `compute` cannot be used together"
+}
+::core::compile_error! {
+    "`each` and `setter` have no effect on a field without a setter"
+}
+::core::compile_error! {
+    "`compute` cannot be used together with `default`"
+}
@@ -0,0 +1,614 @@
+mod process {
+    #[derive(::core::fmt::Debug)]
+    pub struct CommandBuilder {
+        executable: ::core::option::Option<String>,
+        args: ::core::option::Option<Vec<String>>,
+    }
+    impl ::core::default::Default for CommandBuilder {
+        fn default() -> Self {
+            CommandBuilder {
+                executable: ::core::option::Option::None,
+                args: ::core::option::Option::None,
+            }
+        }
+    }
+    impl Command {
+        pub fn builder() -> CommandBuilder {
+            <CommandBuilder as ::core::default::Default>::default()
+        }
+    }
+    impl CommandBuilder {
+        pub fn executable(&mut self, val: String) -> &mut Self {
+            self.executable = ::core::option::Option::Some(val);
+            self
+        }
+        pub fn args(&mut self, val: Vec<String>) -> &mut Self {
+            self.args = ::core::option::Option::Some(val);
+            self
+        }
+        pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+            ::core::option::Option::as_ref(&self.executable)
+        }
+        pub fn is_executable_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.executable)
+        }
+        pub fn clear_executable(&mut self) -> &mut Self {
+            self.executable = ::core::option::Option::None;
+            self
+        }
+        pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+            ::core::option::Option::as_ref(&self.args)
+        }
+        pub fn is_args_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.args)
+        }
+        pub fn clear_args(&mut self) -> &mut Self {
+            self.args = ::core::option::Option::None;
+            self
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if self.executable.is_none() {
+                missing.push("executable");
+            }
+            if self.args.is_none() {
+                missing.push("args");
+            }
+            missing
+        }
+        pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+            CommandBuilderFieldMeta {
+                name: "executable",
+                ty: "String",
+                required: true,
+                doc: "",
+            },
+            CommandBuilderFieldMeta {
+                name: "args",
+                ty: "Vec<String>",
+                required: true,
+                doc: "",
+            },
+        ];
+        pub fn set_by_name(
+            &mut self,
+            name: &str,
+            value: &str,
+        ) -> ::core::result::Result<(), CommandBuilderError>
+        where
+            for<'__a> String: ::core::str::FromStr,
+            for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+            for<'__a> Vec<String>: ::core::str::FromStr,
+            for<'__a> <Vec<String> as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        {
+            match name {
+                "executable" => {
+                    self.executable = ::core::option::Option::Some(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "executable",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    );
+                    ::core::result::Result::Ok(())
+                }
+                "args" => {
+                    self.args = ::core::option::Option::Some(
+                        match <Vec<String> as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "args",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    );
+                    ::core::result::Result::Ok(())
+                }
+                _ => {
+                    ::core::result::Result::Err(
+                        CommandBuilderError::UnknownField(
+                            ::std::string::ToString::to_string(name),
+                        ),
+                    )
+                }
+            }
+        }
+        pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+            let mut missing = ::std::vec::Vec::new();
+            if self.executable.is_none() {
+                missing.push("executable");
+            }
+            if self.args.is_none() {
+                missing.push("args");
+            }
+            if !missing.is_empty() {
+                return ::core::result::Result::Err(
+                    CommandBuilderError::MissingFields(missing),
+                );
+            }
+            let executable = ::core::option::Option::unwrap(
+                ::core::clone::Clone::clone(&self.executable),
+            );
+            let args = ::core::option::Option::unwrap(
+                ::core::clone::Clone::clone(&self.args),
+            );
+            let __built = Command { executable, args };
+            ::core::result::Result::Ok(__built)
+        }
+        #[doc(hidden)]
+        pub fn __try_build(
+            &mut self,
+        ) -> ::core::result::Result<Command, CommandBuilderError> {
+            self.build()
+        }
+    }
+    impl ::core::convert::From<Command> for CommandBuilder {
+        fn from(value: Command) -> Self {
+            CommandBuilder {
+                executable: ::core::option::Option::Some(value.executable),
+                args: ::core::option::Option::Some(value.args),
+            }
+        }
+    }
+    impl Command {
+        pub fn to_builder(&self) -> CommandBuilder
+        where
+            for<'__a> String: ::core::clone::Clone,
+            for<'__a> Vec<String>: ::core::clone::Clone,
+        {
+            CommandBuilder {
+                executable: ::core::option::Option::Some(
+                    ::core::clone::Clone::clone(&self.executable),
+                ),
+                args: ::core::option::Option::Some(
+                    ::core::clone::Clone::clone(&self.args),
+                ),
+            }
+        }
+    }
+    #[derive(
+        ::core::fmt::Debug,
+        ::core::clone::Clone,
+        ::core::cmp::PartialEq,
+        ::core::cmp::Eq,
+    )]
+    #[non_exhaustive]
+    pub enum CommandBuilderError {
+        MissingFields(::std::vec::Vec<&'static str>),
+        InvalidField { field: &'static str, message: ::std::string::String },
+        UnknownField(::std::string::String),
+    }
+    impl CommandBuilderError {
+        #[doc(hidden)]
+        pub fn __nested_errors(
+            &self,
+            field: &str,
+        ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+            let path = |inner: &str| match inner.is_empty() {
+                true => ::std::string::ToString::to_string(field),
+                false => ::std::format!("{}.{}", field, inner),
+            };
+            match self {
+                CommandBuilderError::MissingFields(fields) => {
+                    fields
+                        .iter()
+                        .map(|inner| (
+                            path(inner),
+                            ::std::string::ToString::to_string("missing"),
+                        ))
+                        .collect()
+                }
+                CommandBuilderError::InvalidField { field: inner, message } => {
+                    ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+                }
+                CommandBuilderError::UnknownField(_) => {
+                    ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+                }
+            }
+        }
+    }
+    impl ::core::fmt::Display for CommandBuilderError {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            match self {
+                CommandBuilderError::MissingFields(fields) => {
+                    ::core::write!(f, "missing required fields: {}", fields.join(", "))
+                }
+                CommandBuilderError::InvalidField { field, message } => {
+                    ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+                }
+                CommandBuilderError::UnknownField(name) => {
+                    ::core::write!(f, "unknown field `{}`", name)
+                }
+            }
+        }
+    }
+    impl ::std::error::Error for CommandBuilderError {}
+    #[derive(
+        ::core::fmt::Debug,
+        ::core::clone::Clone,
+        ::core::marker::Copy,
+        ::core::cmp::PartialEq,
+        ::core::cmp::Eq,
+    )]
+    pub struct CommandBuilderFieldMeta {
+        pub name: &'static str,
+        pub ty: &'static str,
+        pub required: bool,
+        pub doc: &'static str,
+    }
+    #[derive(::core::fmt::Debug, Clone, PartialEq)]
+    pub(crate) struct JobConfig {
+        name: ::core::option::Option<String>,
+        retries: ::core::option::Option<u32>,
+    }
+    impl ::core::default::Default for JobConfig {
+        fn default() -> Self {
+            JobConfig {
+                name: ::core::option::Option::None,
+                retries: ::core::option::Option::None,
+            }
+        }
+    }
+    impl Job {
+        pub fn builder() -> JobConfig {
+            <JobConfig as ::core::default::Default>::default()
+        }
+    }
+    impl JobConfig {
+        pub(crate) fn name(&mut self, val: String) -> &mut Self {
+            self.name = ::core::option::Option::Some(val);
+            self
+        }
+        pub(crate) fn retries(&mut self, val: u32) -> &mut Self {
+            self.retries = ::core::option::Option::Some(val);
+            self
+        }
+        pub(crate) fn name_ref(&self) -> ::core::option::Option<&String> {
+            ::core::option::Option::as_ref(&self.name)
+        }
+        pub(crate) fn is_name_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.name)
+        }
+        pub(crate) fn clear_name(&mut self) -> &mut Self {
+            self.name = ::core::option::Option::None;
+            self
+        }
+        pub(crate) fn retries_ref(&self) -> ::core::option::Option<&u32> {
+            ::core::option::Option::as_ref(&self.retries)
+        }
+        pub(crate) fn is_retries_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.retries)
+        }
+        pub(crate) fn clear_retries(&mut self) -> &mut Self {
+            self.retries = ::core::option::Option::None;
+            self
+        }
+        pub(crate) fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if self.name.is_none() {
+                missing.push("name");
+            }
+            missing
+        }
+        pub(crate) const FIELDS: &'static [JobConfigFieldMeta] = &[
+            JobConfigFieldMeta {
+                name: "name",
+                ty: "String",
+                required: true,
+                doc: "",
+            },
+            JobConfigFieldMeta {
+                name: "retries",
+                ty: "Option<u32>",
+                required: false,
+                doc: "",
+            },
+        ];
+        pub(crate) fn set_by_name(
+            &mut self,
+            name: &str,
+            value: &str,
+        ) -> ::core::result::Result<(), JobConfigError>
+        where
+            for<'__a> String: ::core::str::FromStr,
+            for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+            for<'__a> u32: ::core::str::FromStr,
+            for<'__a> <u32 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        {
+            match name {
+                "name" => {
+                    self.name = ::core::option::Option::Some(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(JobConfigError::InvalidField {
+                                    field: "name",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    );
+                    ::core::result::Result::Ok(())
+                }
+                "retries" => {
+                    self.retries = ::core::option::Option::Some(
+                        match <u32 as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(JobConfigError::InvalidField {
+                                    field: "retries",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    );
+                    ::core::result::Result::Ok(())
+                }
+                _ => {
+                    ::core::result::Result::Err(
+                        JobConfigError::UnknownField(
+                            ::std::string::ToString::to_string(name),
+                        ),
+                    )
+                }
+            }
+        }
+        pub(crate) fn finish(&mut self) -> ::core::result::Result<Job, JobConfigError> {
+            let mut missing = ::std::vec::Vec::new();
+            if self.name.is_none() {
+                missing.push("name");
+            }
+            if !missing.is_empty() {
+                return ::core::result::Result::Err(
+                    JobConfigError::MissingFields(missing),
+                );
+            }
+            let name = ::core::option::Option::unwrap(
+                ::core::clone::Clone::clone(&self.name),
+            );
+            let retries = ::core::clone::Clone::clone(&self.retries);
+            let __built = Job { name, retries };
+            ::core::result::Result::Ok(__built)
+        }
+        #[doc(hidden)]
+        pub(crate) fn __try_build(
+            &mut self,
+        ) -> ::core::result::Result<Job, JobConfigError> {
+            self.finish()
+        }
+    }
+    impl ::core::convert::From<Job> for JobConfig {
+        fn from(value: Job) -> Self {
+            JobConfig {
+                name: ::core::option::Option::Some(value.name),
+                retries: value.retries,
+            }
+        }
+    }
+    impl Job {
+        pub fn to_builder(&self) -> JobConfig
+        where
+            for<'__a> String: ::core::clone::Clone,
+            for<'__a> u32: ::core::clone::Clone,
+        {
+            JobConfig {
+                name: ::core::option::Option::Some(
+                    ::core::clone::Clone::clone(&self.name),
+                ),
+                retries: ::core::clone::Clone::clone(&self.retries),
+            }
+        }
+    }
+    #[derive(
+        ::core::fmt::Debug,
+        ::core::clone::Clone,
+        ::core::cmp::PartialEq,
+        ::core::cmp::Eq,
+    )]
+    #[non_exhaustive]
+    pub(crate) enum JobConfigError {
+        MissingFields(::std::vec::Vec<&'static str>),
+        InvalidField { field: &'static str, message: ::std::string::String },
+        UnknownField(::std::string::String),
+    }
+    impl JobConfigError {
+        #[doc(hidden)]
+        pub(crate) fn __nested_errors(
+            &self,
+            field: &str,
+        ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+            let path = |inner: &str| match inner.is_empty() {
+                true => ::std::string::ToString::to_string(field),
+                false => ::std::format!("{}.{}", field, inner),
+            };
+            match self {
+                JobConfigError::MissingFields(fields) => {
+                    fields
+                        .iter()
+                        .map(|inner| (
+                            path(inner),
+                            ::std::string::ToString::to_string("missing"),
+                        ))
+                        .collect()
+                }
+                JobConfigError::InvalidField { field: inner, message } => {
+                    ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+                }
+                JobConfigError::UnknownField(_) => {
+                    ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+                }
+            }
+        }
+    }
+    impl ::core::fmt::Display for JobConfigError {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            match self {
+                JobConfigError::MissingFields(fields) => {
+                    ::core::write!(f, "missing required fields: {}", fields.join(", "))
+                }
+                JobConfigError::InvalidField { field, message } => {
+                    ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+                }
+                JobConfigError::UnknownField(name) => {
+                    ::core::write!(f, "unknown field `{}`", name)
+                }
+            }
+        }
+    }
+    impl ::std::error::Error for JobConfigError {}
+    #[derive(
+        ::core::fmt::Debug,
+        ::core::clone::Clone,
+        ::core::marker::Copy,
+        ::core::cmp::PartialEq,
+        ::core::cmp::Eq,
+    )]
+    pub(crate) struct JobConfigFieldMeta {
+        pub(crate) name: &'static str,
+        pub(crate) ty: &'static str,
+        pub(crate) required: bool,
+        pub(crate) doc: &'static str,
+    }
+    #[allow(non_camel_case_types)]
+    #[derive(::core::fmt::Debug, Clone)]
+    pub struct ServerBuilder<__host = ()> {
+        host: __host,
+        __marker: ::core::marker::PhantomData<fn() -> Server>,
+    }
+    impl Server {
+        pub fn builder() -> ServerBuilder {
+            ServerBuilder {
+                host: (),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__host> ServerBuilder<__host> {
+        pub fn host(self, val: String) -> ServerBuilder<(String,)> {
+            ServerBuilder {
+                host: (val,),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+    }
+    impl ServerBuilder {
+        pub const FIELDS: &'static [ServerBuilderFieldMeta] = &[
+            ServerBuilderFieldMeta {
+                name: "host",
+                ty: "String",
+                required: true,
+                doc: "",
+            },
+        ];
+    }
+    impl ::core::convert::From<Server> for ServerBuilder<(String,)> {
+        fn from(value: Server) -> Self {
+            ServerBuilder {
+                host: (value.host,),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+    }
+    impl Server {
+        pub fn to_builder(&self) -> ServerBuilder<(String,)>
+        where
+            for<'__a> String: ::core::clone::Clone,
+        {
+            ServerBuilder {
+                host: (::core::clone::Clone::clone(&self.host),),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+    }
+    const _: () = {
+        #[allow(non_camel_case_types)]
+        #[diagnostic::on_unimplemented(
+            message = "`ServerBuilder::finish()` requires field `host` to be set",
+            label = "call `.host(..)` before `.finish()`"
+        )]
+        pub trait ServerBuilder_host_is_set<T> {
+            fn value(self) -> T;
+        }
+        impl<T> ServerBuilder_host_is_set<T> for (T,) {
+            fn value(self) -> T {
+                self.0
+            }
+        }
+        #[allow(non_camel_case_types)]
+        pub trait ServerBuilder_maybe_set<T> {
+            fn value(self) -> ::core::option::Option<T>;
+            fn value_ref(&self) -> ::core::option::Option<&T>;
+        }
+        impl<T> ServerBuilder_maybe_set<T> for () {
+            fn value(self) -> ::core::option::Option<T> {
+                ::core::option::Option::None
+            }
+            fn value_ref(&self) -> ::core::option::Option<&T> {
+                ::core::option::Option::None
+            }
+        }
+        impl<T> ServerBuilder_maybe_set<T> for (T,) {
+            fn value(self) -> ::core::option::Option<T> {
+                ::core::option::Option::Some(self.0)
+            }
+            fn value_ref(&self) -> ::core::option::Option<&T> {
+                ::core::option::Option::Some(&self.0)
+            }
+        }
+        #[allow(non_camel_case_types)]
+        impl<__host> ServerBuilder<__host>
+        where
+            __host: ServerBuilder_maybe_set<String>,
+        {
+            pub fn host_ref(&self) -> ::core::option::Option<&String> {
+                ServerBuilder_maybe_set::<String>::value_ref(&self.host)
+            }
+            pub fn is_host_set(&self) -> bool {
+                ::core::option::Option::is_some(&self.host_ref())
+            }
+            pub fn clear_host(self) -> ServerBuilder<()> {
+                ServerBuilder {
+                    host: (),
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+            pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+                let mut missing = ::std::vec::Vec::new();
+                if !self.is_host_set() {
+                    missing.push("host");
+                }
+                missing
+            }
+        }
+        #[allow(non_camel_case_types)]
+        impl<__host> ServerBuilder<__host> {
+            pub fn finish(self) -> Server
+            where
+                __host: ServerBuilder_host_is_set<String>,
+            {
+                let host = ServerBuilder_host_is_set::value(self.host);
+                Server { host }
+            }
+        }
+    };
+    #[derive(
+        ::core::fmt::Debug,
+        ::core::clone::Clone,
+        ::core::marker::Copy,
+        ::core::cmp::PartialEq,
+        ::core::cmp::Eq,
+    )]
+    pub struct ServerBuilderFieldMeta {
+        pub name: &'static str,
+        pub ty: &'static str,
+        pub required: bool,
+        pub doc: &'static str,
+    }
+}
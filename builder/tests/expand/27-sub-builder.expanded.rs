@@ -0,0 +1,931 @@
+mod tls {
+    #[derive(::core::fmt::Debug)]
+    pub struct TlsConfigBuilder {
+        cert: ::core::option::Option<String>,
+        key: ::core::option::Option<String>,
+    }
+    impl ::core::default::Default for TlsConfigBuilder {
+        fn default() -> Self {
+            TlsConfigBuilder {
+                cert: ::core::option::Option::None,
+                key: ::core::option::Option::None,
+            }
+        }
+    }
+    impl TlsConfig {
+        pub fn builder() -> TlsConfigBuilder {
+            <TlsConfigBuilder as ::core::default::Default>::default()
+        }
+    }
+    impl TlsConfigBuilder {
+        pub fn cert(&mut self, val: impl ::core::convert::Into<String>) -> &mut Self {
+            self.cert = ::core::option::Option::Some(::core::convert::Into::into(val));
+            self
+        }
+        pub fn key(&mut self, val: impl ::core::convert::Into<String>) -> &mut Self {
+            self.key = ::core::option::Option::Some(::core::convert::Into::into(val));
+            self
+        }
+        pub fn cert_ref(&self) -> ::core::option::Option<&String> {
+            ::core::option::Option::as_ref(&self.cert)
+        }
+        pub fn is_cert_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.cert)
+        }
+        pub fn clear_cert(&mut self) -> &mut Self {
+            self.cert = ::core::option::Option::None;
+            self
+        }
+        pub fn key_ref(&self) -> ::core::option::Option<&String> {
+            ::core::option::Option::as_ref(&self.key)
+        }
+        pub fn is_key_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.key)
+        }
+        pub fn clear_key(&mut self) -> &mut Self {
+            self.key = ::core::option::Option::None;
+            self
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if self.cert.is_none() {
+                missing.push("cert");
+            }
+            if self.key.is_none() {
+                missing.push("key");
+            }
+            missing
+        }
+        pub const FIELDS: &'static [TlsConfigBuilderFieldMeta] = &[
+            TlsConfigBuilderFieldMeta {
+                name: "cert",
+                ty: "String",
+                required: true,
+                doc: "",
+            },
+            TlsConfigBuilderFieldMeta {
+                name: "key",
+                ty: "String",
+                required: true,
+                doc: "",
+            },
+        ];
+        pub fn set_by_name(
+            &mut self,
+            name: &str,
+            value: &str,
+        ) -> ::core::result::Result<(), TlsConfigBuilderError>
+        where
+            for<'__a> String: ::core::str::FromStr,
+            for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+            for<'__a> String: ::core::str::FromStr,
+            for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        {
+            match name {
+                "cert" => {
+                    self.cert = ::core::option::Option::Some(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(TlsConfigBuilderError::InvalidField {
+                                    field: "cert",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    );
+                    ::core::result::Result::Ok(())
+                }
+                "key" => {
+                    self.key = ::core::option::Option::Some(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(TlsConfigBuilderError::InvalidField {
+                                    field: "key",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    );
+                    ::core::result::Result::Ok(())
+                }
+                _ => {
+                    ::core::result::Result::Err(
+                        TlsConfigBuilderError::UnknownField(
+                            ::std::string::ToString::to_string(name),
+                        ),
+                    )
+                }
+            }
+        }
+        pub fn build(
+            &mut self,
+        ) -> ::core::result::Result<TlsConfig, TlsConfigBuilderError> {
+            let mut missing = ::std::vec::Vec::new();
+            if self.cert.is_none() {
+                missing.push("cert");
+            }
+            if self.key.is_none() {
+                missing.push("key");
+            }
+            if !missing.is_empty() {
+                return ::core::result::Result::Err(
+                    TlsConfigBuilderError::MissingFields(missing),
+                );
+            }
+            let cert = ::core::option::Option::unwrap(
+                ::core::clone::Clone::clone(&self.cert),
+            );
+            let key = ::core::option::Option::unwrap(
+                ::core::clone::Clone::clone(&self.key),
+            );
+            let __built = TlsConfig { cert, key };
+            ::core::result::Result::Ok(__built)
+        }
+        #[doc(hidden)]
+        pub fn __try_build(
+            &mut self,
+        ) -> ::core::result::Result<TlsConfig, TlsConfigBuilderError> {
+            self.build()
+        }
+    }
+    impl ::core::convert::From<TlsConfig> for TlsConfigBuilder {
+        fn from(value: TlsConfig) -> Self {
+            TlsConfigBuilder {
+                cert: ::core::option::Option::Some(value.cert),
+                key: ::core::option::Option::Some(value.key),
+            }
+        }
+    }
+    impl TlsConfig {
+        pub fn to_builder(&self) -> TlsConfigBuilder
+        where
+            for<'__a> String: ::core::clone::Clone,
+            for<'__a> String: ::core::clone::Clone,
+        {
+            TlsConfigBuilder {
+                cert: ::core::option::Option::Some(
+                    ::core::clone::Clone::clone(&self.cert),
+                ),
+                key: ::core::option::Option::Some(::core::clone::Clone::clone(&self.key)),
+            }
+        }
+    }
+    #[derive(
+        ::core::fmt::Debug,
+        ::core::clone::Clone,
+        ::core::cmp::PartialEq,
+        ::core::cmp::Eq,
+    )]
+    #[non_exhaustive]
+    pub enum TlsConfigBuilderError {
+        MissingFields(::std::vec::Vec<&'static str>),
+        InvalidField { field: &'static str, message: ::std::string::String },
+        UnknownField(::std::string::String),
+    }
+    impl TlsConfigBuilderError {
+        #[doc(hidden)]
+        pub fn __nested_errors(
+            &self,
+            field: &str,
+        ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+            let path = |inner: &str| match inner.is_empty() {
+                true => ::std::string::ToString::to_string(field),
+                false => ::std::format!("{}.{}", field, inner),
+            };
+            match self {
+                TlsConfigBuilderError::MissingFields(fields) => {
+                    fields
+                        .iter()
+                        .map(|inner| (
+                            path(inner),
+                            ::std::string::ToString::to_string("missing"),
+                        ))
+                        .collect()
+                }
+                TlsConfigBuilderError::InvalidField { field: inner, message } => {
+                    ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+                }
+                TlsConfigBuilderError::UnknownField(_) => {
+                    ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+                }
+            }
+        }
+    }
+    impl ::core::fmt::Display for TlsConfigBuilderError {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            match self {
+                TlsConfigBuilderError::MissingFields(fields) => {
+                    ::core::write!(f, "missing required fields: {}", fields.join(", "))
+                }
+                TlsConfigBuilderError::InvalidField { field, message } => {
+                    ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+                }
+                TlsConfigBuilderError::UnknownField(name) => {
+                    ::core::write!(f, "unknown field `{}`", name)
+                }
+            }
+        }
+    }
+    impl ::std::error::Error for TlsConfigBuilderError {}
+    #[derive(
+        ::core::fmt::Debug,
+        ::core::clone::Clone,
+        ::core::marker::Copy,
+        ::core::cmp::PartialEq,
+        ::core::cmp::Eq,
+    )]
+    pub struct TlsConfigBuilderFieldMeta {
+        pub name: &'static str,
+        pub ty: &'static str,
+        pub required: bool,
+        pub doc: &'static str,
+    }
+}
+#[derive(::core::fmt::Debug)]
+pub struct RetryPolicyBuilder {
+    attempts: ::core::option::Option<u32>,
+}
+impl ::core::default::Default for RetryPolicyBuilder {
+    fn default() -> Self {
+        RetryPolicyBuilder {
+            attempts: ::core::option::Option::None,
+        }
+    }
+}
+impl RetryPolicy {
+    pub fn builder() -> RetryPolicyBuilder {
+        <RetryPolicyBuilder as ::core::default::Default>::default()
+    }
+}
+impl RetryPolicyBuilder {
+    pub fn attempts(&mut self, val: u32) -> &mut Self {
+        self.attempts = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn attempts_ref(&self) -> ::core::option::Option<&u32> {
+        ::core::option::Option::as_ref(&self.attempts)
+    }
+    pub fn is_attempts_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.attempts)
+    }
+    pub fn clear_attempts(&mut self) -> &mut Self {
+        self.attempts = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        missing
+    }
+    pub const FIELDS: &'static [RetryPolicyBuilderFieldMeta] = &[
+        RetryPolicyBuilderFieldMeta {
+            name: "attempts",
+            ty: "u32",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), RetryPolicyBuilderError>
+    where
+        for<'__a> u32: ::core::str::FromStr,
+        for<'__a> <u32 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "attempts" => {
+                self.attempts = ::core::option::Option::Some(
+                    match <u32 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(RetryPolicyBuilderError::InvalidField {
+                                field: "attempts",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    RetryPolicyBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(
+        &mut self,
+    ) -> ::core::result::Result<RetryPolicy, RetryPolicyBuilderError> {
+        let attempts = match ::core::clone::Clone::clone(&self.attempts) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => 3,
+        };
+        let __built = RetryPolicy { attempts };
+        if let ::core::result::Result::Err(err) = positive_attempts(&__built) {
+            return ::core::result::Result::Err(
+                RetryPolicyBuilderError::Invalid(
+                    ::std::string::ToString::to_string(&err),
+                ),
+            );
+        }
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<RetryPolicy, RetryPolicyBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<RetryPolicy> for RetryPolicyBuilder {
+    fn from(value: RetryPolicy) -> Self {
+        RetryPolicyBuilder {
+            attempts: ::core::option::Option::Some(value.attempts),
+        }
+    }
+}
+impl RetryPolicy {
+    pub fn to_builder(&self) -> RetryPolicyBuilder
+    where
+        for<'__a> u32: ::core::clone::Clone,
+    {
+        RetryPolicyBuilder {
+            attempts: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.attempts),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum RetryPolicyBuilderError {
+    InvalidField { field: &'static str, message: ::std::string::String },
+    Invalid(::std::string::String),
+    UnknownField(::std::string::String),
+}
+impl RetryPolicyBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            RetryPolicyBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            RetryPolicyBuilderError::Invalid(message) => {
+                ::std::vec![(path(""), ::core::clone::Clone::clone(message))]
+            }
+            RetryPolicyBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for RetryPolicyBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            RetryPolicyBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            RetryPolicyBuilderError::Invalid(message) => {
+                ::core::write!(f, "validation failed: {}", message)
+            }
+            RetryPolicyBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for RetryPolicyBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct RetryPolicyBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct ServiceConfigBuilder {
+    name: ::core::option::Option<String>,
+    tls: tls::TlsConfigBuilder,
+    retry: RetryPolicyBuilder,
+}
+impl ::core::default::Default for ServiceConfigBuilder {
+    fn default() -> Self {
+        ServiceConfigBuilder {
+            name: ::core::option::Option::None,
+            tls: ::core::default::Default::default(),
+            retry: ::core::default::Default::default(),
+        }
+    }
+}
+impl ServiceConfig {
+    pub fn builder() -> ServiceConfigBuilder {
+        <ServiceConfigBuilder as ::core::default::Default>::default()
+    }
+}
+impl ServiceConfigBuilder {
+    pub fn name(&mut self, val: String) -> &mut Self {
+        self.name = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn tls<__F>(&mut self, configure: __F) -> &mut Self
+    where
+        __F: ::core::ops::FnOnce(
+            &mut tls::TlsConfigBuilder,
+        ) -> &mut tls::TlsConfigBuilder,
+    {
+        configure(&mut self.tls);
+        self
+    }
+    pub fn tls_mut(&mut self) -> &mut tls::TlsConfigBuilder {
+        &mut self.tls
+    }
+    pub fn retry<__F>(&mut self, configure: __F) -> &mut Self
+    where
+        __F: ::core::ops::FnOnce(&mut RetryPolicyBuilder) -> &mut RetryPolicyBuilder,
+    {
+        configure(&mut self.retry);
+        self
+    }
+    pub fn retry_mut(&mut self) -> &mut RetryPolicyBuilder {
+        &mut self.retry
+    }
+    pub fn name_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.name)
+    }
+    pub fn is_name_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.name)
+    }
+    pub fn clear_name(&mut self) -> &mut Self {
+        self.name = ::core::option::Option::None;
+        self
+    }
+    pub fn tls_ref(&self) -> &tls::TlsConfigBuilder {
+        &self.tls
+    }
+    pub fn clear_tls(&mut self) -> &mut Self {
+        self.tls = ::core::default::Default::default();
+        self
+    }
+    pub fn retry_ref(&self) -> &RetryPolicyBuilder {
+        &self.retry
+    }
+    pub fn clear_retry(&mut self) -> &mut Self {
+        self.retry = ::core::default::Default::default();
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [ServiceConfigBuilderFieldMeta] = &[
+        ServiceConfigBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        ServiceConfigBuilderFieldMeta {
+            name: "tls",
+            ty: "tls::TlsConfig",
+            required: false,
+            doc: "",
+        },
+        ServiceConfigBuilderFieldMeta {
+            name: "retry",
+            ty: "RetryPolicy",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), ServiceConfigBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "name" => {
+                self.name = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(ServiceConfigBuilderError::InvalidField {
+                                field: "name",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "tls" => {
+                ::core::result::Result::Err(ServiceConfigBuilderError::InvalidField {
+                    field: "tls",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "retry" => {
+                ::core::result::Result::Err(ServiceConfigBuilderError::InvalidField {
+                    field: "retry",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    ServiceConfigBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(
+        &mut self,
+    ) -> ::core::result::Result<ServiceConfig, ServiceConfigBuilderError> {
+        let __sub_tls = self.tls.__try_build();
+        let __sub_retry = self.retry.__try_build();
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        if !missing.is_empty() {
+            let mut nested = ::std::vec::Vec::new();
+            if let ::core::result::Result::Err(err) = &__sub_tls {
+                nested.extend(err.__nested_errors("tls"));
+            }
+            if let ::core::result::Result::Err(err) = &__sub_retry {
+                nested.extend(err.__nested_errors("retry"));
+            }
+            if !nested.is_empty() {
+                let mut errors: ::std::vec::Vec<_> = missing
+                    .iter()
+                    .map(|field| (
+                        ::std::string::ToString::to_string(field),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect();
+                errors.extend(nested);
+                return ::core::result::Result::Err(
+                    ServiceConfigBuilderError::Nested(errors),
+                );
+            }
+            return ::core::result::Result::Err(
+                ServiceConfigBuilderError::MissingFields(missing),
+            );
+        }
+        let name = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.name),
+        );
+        let tls = match __sub_tls {
+            ::core::result::Result::Ok(__value) => __value,
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(
+                    ServiceConfigBuilderError::Nested(err.__nested_errors("tls")),
+                );
+            }
+        };
+        let retry = match __sub_retry {
+            ::core::result::Result::Ok(__value) => __value,
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(
+                    ServiceConfigBuilderError::Nested(err.__nested_errors("retry")),
+                );
+            }
+        };
+        let __built = ServiceConfig { name, tls, retry };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<ServiceConfig, ServiceConfigBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<ServiceConfig> for ServiceConfigBuilder {
+    fn from(value: ServiceConfig) -> Self {
+        ServiceConfigBuilder {
+            name: ::core::option::Option::Some(value.name),
+            tls: ::core::convert::From::from(value.tls),
+            retry: ::core::convert::From::from(value.retry),
+        }
+    }
+}
+impl ServiceConfig {
+    pub fn to_builder(&self) -> ServiceConfigBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> tls::TlsConfig: ::core::clone::Clone,
+        for<'__a> RetryPolicy: ::core::clone::Clone,
+    {
+        ServiceConfigBuilder {
+            name: ::core::option::Option::Some(::core::clone::Clone::clone(&self.name)),
+            tls: ::core::convert::From::from(::core::clone::Clone::clone(&self.tls)),
+            retry: ::core::convert::From::from(::core::clone::Clone::clone(&self.retry)),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum ServiceConfigBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+    Nested(::std::vec::Vec<(::std::string::String, ::std::string::String)>),
+}
+impl ServiceConfigBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            ServiceConfigBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            ServiceConfigBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            ServiceConfigBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+            ServiceConfigBuilderError::Nested(errors) => {
+                errors
+                    .iter()
+                    .map(|(inner, message)| (
+                        path(inner),
+                        ::core::clone::Clone::clone(message),
+                    ))
+                    .collect()
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for ServiceConfigBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ServiceConfigBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            ServiceConfigBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            ServiceConfigBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+            ServiceConfigBuilderError::Nested(errors) => {
+                for (i, (path, message)) in errors.iter().enumerate() {
+                    if i > 0 {
+                        ::core::write!(f, ", ")?;
+                    }
+                    ::core::write!(f, "{}: {}", path, message)?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl ::std::error::Error for ServiceConfigBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct ServiceConfigBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct DeploymentBuilder {
+    service: ServiceConfigBuilder,
+}
+impl ::core::default::Default for DeploymentBuilder {
+    fn default() -> Self {
+        DeploymentBuilder {
+            service: ::core::default::Default::default(),
+        }
+    }
+}
+impl Deployment {
+    pub fn builder() -> DeploymentBuilder {
+        <DeploymentBuilder as ::core::default::Default>::default()
+    }
+}
+impl DeploymentBuilder {
+    pub fn service<__F>(mut self, configure: __F) -> Self
+    where
+        __F: ::core::ops::FnOnce(&mut ServiceConfigBuilder) -> &mut ServiceConfigBuilder,
+    {
+        configure(&mut self.service);
+        self
+    }
+    pub fn service_mut(&mut self) -> &mut ServiceConfigBuilder {
+        &mut self.service
+    }
+    pub fn service_ref(&self) -> &ServiceConfigBuilder {
+        &self.service
+    }
+    pub fn clear_service(mut self) -> Self {
+        self.service = ::core::default::Default::default();
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        missing
+    }
+    pub const FIELDS: &'static [DeploymentBuilderFieldMeta] = &[
+        DeploymentBuilderFieldMeta {
+            name: "service",
+            ty: "ServiceConfig",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), DeploymentBuilderError> {
+        match name {
+            "service" => {
+                ::core::result::Result::Err(DeploymentBuilderError::InvalidField {
+                    field: "service",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    DeploymentBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(self) -> ::core::result::Result<Deployment, DeploymentBuilderError> {
+        let __sub_service = {
+            let mut __sub_builder = self.service;
+            __sub_builder.__try_build()
+        };
+        let service = match __sub_service {
+            ::core::result::Result::Ok(__value) => __value,
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(
+                    DeploymentBuilderError::Nested(err.__nested_errors("service")),
+                );
+            }
+        };
+        let __built = Deployment { service };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        self,
+    ) -> ::core::result::Result<Deployment, DeploymentBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Deployment> for DeploymentBuilder {
+    fn from(value: Deployment) -> Self {
+        DeploymentBuilder {
+            service: ::core::convert::From::from(value.service),
+        }
+    }
+}
+impl Deployment {
+    pub fn to_builder(&self) -> DeploymentBuilder
+    where
+        for<'__a> ServiceConfig: ::core::clone::Clone,
+    {
+        DeploymentBuilder {
+            service: ::core::convert::From::from(
+                ::core::clone::Clone::clone(&self.service),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum DeploymentBuilderError {
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+    Nested(::std::vec::Vec<(::std::string::String, ::std::string::String)>),
+}
+impl DeploymentBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            DeploymentBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            DeploymentBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+            DeploymentBuilderError::Nested(errors) => {
+                errors
+                    .iter()
+                    .map(|(inner, message)| (
+                        path(inner),
+                        ::core::clone::Clone::clone(message),
+                    ))
+                    .collect()
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for DeploymentBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            DeploymentBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            DeploymentBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+            DeploymentBuilderError::Nested(errors) => {
+                for (i, (path, message)) in errors.iter().enumerate() {
+                    if i > 0 {
+                        ::core::write!(f, ", ")?;
+                    }
+                    ::core::write!(f, "{}: {}", path, message)?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl ::std::error::Error for DeploymentBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct DeploymentBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
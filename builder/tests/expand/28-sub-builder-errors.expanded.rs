@@ -0,0 +1,198 @@
+#[derive(::core::fmt::Debug)]
+pub struct TlsConfigBuilder {
+    cert: ::core::option::Option<String>,
+}
+impl ::core::default::Default for TlsConfigBuilder {
+    fn default() -> Self {
+        TlsConfigBuilder {
+            cert: ::core::option::Option::None,
+        }
+    }
+}
+impl TlsConfig {
+    pub fn builder() -> TlsConfigBuilder {
+        <TlsConfigBuilder as ::core::default::Default>::default()
+    }
+}
+impl TlsConfigBuilder {
+    pub fn cert(&mut self, val: String) -> &mut Self {
+        self.cert = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn cert_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.cert)
+    }
+    pub fn is_cert_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.cert)
+    }
+    pub fn clear_cert(&mut self) -> &mut Self {
+        self.cert = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.cert.is_none() {
+            missing.push("cert");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [TlsConfigBuilderFieldMeta] = &[
+        TlsConfigBuilderFieldMeta {
+            name: "cert",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), TlsConfigBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "cert" => {
+                self.cert = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(TlsConfigBuilderError::InvalidField {
+                                field: "cert",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    TlsConfigBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<TlsConfig, TlsConfigBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.cert.is_none() {
+            missing.push("cert");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                TlsConfigBuilderError::MissingFields(missing),
+            );
+        }
+        let cert = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.cert),
+        );
+        let __built = TlsConfig { cert };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<TlsConfig, TlsConfigBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<TlsConfig> for TlsConfigBuilder {
+    fn from(value: TlsConfig) -> Self {
+        TlsConfigBuilder {
+            cert: ::core::option::Option::Some(value.cert),
+        }
+    }
+}
+impl TlsConfig {
+    pub fn to_builder(&self) -> TlsConfigBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+    {
+        TlsConfigBuilder {
+            cert: ::core::option::Option::Some(::core::clone::Clone::clone(&self.cert)),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum TlsConfigBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl TlsConfigBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            TlsConfigBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            TlsConfigBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            TlsConfigBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for TlsConfigBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            TlsConfigBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            TlsConfigBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            TlsConfigBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for TlsConfigBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct TlsConfigBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+::core::compile_error! {
+    "`sub_builder` cannot be combined with Option fields, `each`, `default`, `skip` or `compute`"
+}
+::core::compile_error! {
+    "`sub_builder` requires a struct type, or the builder type given as `sub_builder = \"...\"`"
+}
+::core::compile_error! {
+    "`sub_builder` is not supported by typestate builders"
+}
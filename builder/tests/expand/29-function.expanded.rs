@@ -0,0 +1,1447 @@
+pub fn spawn(
+    executable: String,
+    args: Vec<String>,
+    current_dir: Option<String>,
+    priority: u8,
+) -> Child {
+    let mut command_line = format!("{} {}", executable, args.join(" "));
+    if let Some(dir) = current_dir {
+        command_line = format!("cd {} && {}", dir, command_line);
+    }
+    Child {
+        command_line: format!("nice -n {} {}", priority, command_line),
+    }
+}
+pub fn spawn_builder() -> SpawnBuilder {
+    <SpawnBuilder as ::core::default::Default>::default()
+}
+#[derive(::core::fmt::Debug)]
+pub struct SpawnBuilder {
+    executable: ::core::option::Option<String>,
+    args: ::core::option::Option<Vec<String>>,
+    current_dir: ::core::option::Option<String>,
+    priority: ::core::option::Option<u8>,
+}
+impl ::core::default::Default for SpawnBuilder {
+    fn default() -> Self {
+        SpawnBuilder {
+            executable: ::core::option::Option::None,
+            args: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+            priority: ::core::option::Option::None,
+        }
+    }
+}
+impl SpawnBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn args(&mut self, val: Vec<String>) -> &mut Self {
+        self.args = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn arg(&mut self, val: String) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_args<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn current_dir(&mut self, val: String) -> &mut Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn priority(&mut self, val: u8) -> &mut Self {
+        self.priority = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.args)
+    }
+    pub fn is_args_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.args)
+    }
+    pub fn clear_args(&mut self) -> &mut Self {
+        self.args = ::core::option::Option::None;
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(&mut self) -> &mut Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+    pub fn priority_ref(&self) -> ::core::option::Option<&u8> {
+        ::core::option::Option::as_ref(&self.priority)
+    }
+    pub fn is_priority_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.priority)
+    }
+    pub fn clear_priority(&mut self) -> &mut Self {
+        self.priority = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [SpawnBuilderFieldMeta] = &[
+        SpawnBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        SpawnBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+        SpawnBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+        SpawnBuilderFieldMeta {
+            name: "priority",
+            ty: "u8",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), SpawnBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u8: ::core::str::FromStr,
+        for<'__a> <u8 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(SpawnBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "args" => {
+                ::core::iter::Extend::extend(
+                    self.args.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(SpawnBuilderError::InvalidField {
+                                    field: "args",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "current_dir" => {
+                self.current_dir = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(SpawnBuilderError::InvalidField {
+                                field: "current_dir",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "priority" => {
+                self.priority = ::core::option::Option::Some(
+                    match <u8 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(SpawnBuilderError::InvalidField {
+                                field: "priority",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    SpawnBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn call(&mut self) -> ::core::result::Result<Child, SpawnBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                SpawnBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let args = match ::core::clone::Clone::clone(&self.args) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let current_dir = ::core::clone::Clone::clone(&self.current_dir);
+        let priority = match ::core::clone::Clone::clone(&self.priority) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => 1,
+        };
+        ::core::result::Result::Ok(spawn(executable, args, current_dir, priority))
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum SpawnBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl SpawnBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            SpawnBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            SpawnBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            SpawnBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for SpawnBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            SpawnBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            SpawnBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            SpawnBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for SpawnBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct SpawnBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+fn greet<T: std::fmt::Display>(name: T, greeting: Option<String>) -> String {
+    format!("{}, {}!", greeting.as_deref().unwrap_or("Hello"), name)
+}
+fn greet_builder<T: std::fmt::Display>() -> GreetBuilder<T> {
+    <GreetBuilder<T> as ::core::default::Default>::default()
+}
+#[derive(::core::fmt::Debug)]
+struct GreetBuilder<T: std::fmt::Display> {
+    name: ::core::option::Option<T>,
+    greeting: ::core::option::Option<String>,
+}
+impl<T: std::fmt::Display> ::core::default::Default for GreetBuilder<T> {
+    fn default() -> Self {
+        GreetBuilder {
+            name: ::core::option::Option::None,
+            greeting: ::core::option::Option::None,
+        }
+    }
+}
+impl<T: std::fmt::Display> GreetBuilder<T> {
+    fn name(mut self, val: T) -> Self {
+        self.name = ::core::option::Option::Some(val);
+        self
+    }
+    fn greeting(mut self, val: impl ::core::convert::Into<String>) -> Self {
+        self.greeting = ::core::option::Option::Some(::core::convert::Into::into(val));
+        self
+    }
+    fn name_ref(&self) -> ::core::option::Option<&T> {
+        ::core::option::Option::as_ref(&self.name)
+    }
+    fn is_name_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.name)
+    }
+    fn clear_name(mut self) -> Self {
+        self.name = ::core::option::Option::None;
+        self
+    }
+    fn greeting_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.greeting)
+    }
+    fn is_greeting_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.greeting)
+    }
+    fn clear_greeting(mut self) -> Self {
+        self.greeting = ::core::option::Option::None;
+        self
+    }
+    fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        missing
+    }
+    const FIELDS: &'static [GreetBuilderFieldMeta] = &[
+        GreetBuilderFieldMeta {
+            name: "name",
+            ty: "T",
+            required: true,
+            doc: "",
+        },
+        GreetBuilderFieldMeta {
+            name: "greeting",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+    fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), GreetBuilderError>
+    where
+        for<'__a> T: ::core::str::FromStr,
+        for<'__a> <T as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "name" => {
+                self.name = ::core::option::Option::Some(
+                    match <T as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(GreetBuilderError::InvalidField {
+                                field: "name",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "greeting" => {
+                self.greeting = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(GreetBuilderError::InvalidField {
+                                field: "greeting",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    GreetBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    fn run(self) -> ::core::result::Result<String, GreetBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                GreetBuilderError::MissingFields(missing),
+            );
+        }
+        let name = ::core::option::Option::unwrap(self.name);
+        let greeting = self.greeting;
+        ::core::result::Result::Ok(greet(name, greeting))
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+enum GreetBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl GreetBuilderError {
+    #[doc(hidden)]
+    fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            GreetBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            GreetBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            GreetBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for GreetBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            GreetBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            GreetBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            GreetBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for GreetBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+struct GreetBuilderFieldMeta {
+    name: &'static str,
+    ty: &'static str,
+    required: bool,
+    doc: &'static str,
+}
+fn first_word<'t>(text: &'t str, separator: &str) -> &'t str {
+    text.split(separator).next().unwrap_or(text)
+}
+fn first_word_builder<'__a0, 't>() -> FirstWordBuilder<'__a0, 't> {
+    <FirstWordBuilder<'__a0, 't> as ::core::default::Default>::default()
+}
+#[derive(::core::fmt::Debug)]
+struct FirstWordBuilder<'__a0, 't> {
+    text: ::core::option::Option<&'t str>,
+    separator: ::core::option::Option<&'__a0 str>,
+}
+impl<'__a0, 't> ::core::default::Default for FirstWordBuilder<'__a0, 't> {
+    fn default() -> Self {
+        FirstWordBuilder {
+            text: ::core::option::Option::None,
+            separator: ::core::option::Option::None,
+        }
+    }
+}
+impl<'__a0, 't> FirstWordBuilder<'__a0, 't> {
+    fn text(&mut self, val: &'t str) -> &mut Self {
+        self.text = ::core::option::Option::Some(val);
+        self
+    }
+    fn separator(&mut self, val: &'__a0 str) -> &mut Self {
+        self.separator = ::core::option::Option::Some(val);
+        self
+    }
+    fn text_ref(&self) -> ::core::option::Option<&&'t str> {
+        ::core::option::Option::as_ref(&self.text)
+    }
+    fn is_text_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.text)
+    }
+    fn clear_text(&mut self) -> &mut Self {
+        self.text = ::core::option::Option::None;
+        self
+    }
+    fn separator_ref(&self) -> ::core::option::Option<&&'__a0 str> {
+        ::core::option::Option::as_ref(&self.separator)
+    }
+    fn is_separator_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.separator)
+    }
+    fn clear_separator(&mut self) -> &mut Self {
+        self.separator = ::core::option::Option::None;
+        self
+    }
+    fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.text.is_none() {
+            missing.push("text");
+        }
+        missing
+    }
+    const FIELDS: &'static [FirstWordBuilderFieldMeta] = &[
+        FirstWordBuilderFieldMeta {
+            name: "text",
+            ty: "&'t str",
+            required: true,
+            doc: "",
+        },
+        FirstWordBuilderFieldMeta {
+            name: "separator",
+            ty: "&'__a0 str",
+            required: false,
+            doc: "",
+        },
+    ];
+    fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), FirstWordBuilderError> {
+        match name {
+            "text" => {
+                ::core::result::Result::Err(FirstWordBuilderError::InvalidField {
+                    field: "text",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "separator" => {
+                ::core::result::Result::Err(FirstWordBuilderError::InvalidField {
+                    field: "separator",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    FirstWordBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    fn call(&mut self) -> ::core::result::Result<&'t str, FirstWordBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.text.is_none() {
+            missing.push("text");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                FirstWordBuilderError::MissingFields(missing),
+            );
+        }
+        let text = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.text),
+        );
+        let separator = match ::core::clone::Clone::clone(&self.separator) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => " ",
+        };
+        ::core::result::Result::Ok(first_word(text, separator))
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+enum FirstWordBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl FirstWordBuilderError {
+    #[doc(hidden)]
+    fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            FirstWordBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            FirstWordBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            FirstWordBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for FirstWordBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            FirstWordBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            FirstWordBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            FirstWordBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for FirstWordBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+struct FirstWordBuilderFieldMeta {
+    name: &'static str,
+    ty: &'static str,
+    required: bool,
+    doc: &'static str,
+}
+fn longest<'w>(words: &[&'w str]) -> &'w str {
+    words.iter().copied().max_by_key(|word| word.len()).unwrap_or("")
+}
+fn longest_builder<'__a0, 'w>() -> LongestBuilder<'__a0, 'w> {
+    <LongestBuilder<'__a0, 'w> as ::core::default::Default>::default()
+}
+#[derive(::core::fmt::Debug)]
+struct LongestBuilder<'__a0, 'w> {
+    words: ::core::option::Option<&'__a0 [&'w str]>,
+}
+impl<'__a0, 'w> ::core::default::Default for LongestBuilder<'__a0, 'w> {
+    fn default() -> Self {
+        LongestBuilder {
+            words: ::core::option::Option::None,
+        }
+    }
+}
+impl<'__a0, 'w> LongestBuilder<'__a0, 'w> {
+    fn words(&mut self, val: &'__a0 [&'w str]) -> &mut Self {
+        self.words = ::core::option::Option::Some(val);
+        self
+    }
+    fn words_ref(&self) -> ::core::option::Option<&&'__a0 [&'w str]> {
+        ::core::option::Option::as_ref(&self.words)
+    }
+    fn is_words_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.words)
+    }
+    fn clear_words(&mut self) -> &mut Self {
+        self.words = ::core::option::Option::None;
+        self
+    }
+    fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.words.is_none() {
+            missing.push("words");
+        }
+        missing
+    }
+    const FIELDS: &'static [LongestBuilderFieldMeta] = &[
+        LongestBuilderFieldMeta {
+            name: "words",
+            ty: "&'__a0 [&'w str]",
+            required: true,
+            doc: "",
+        },
+    ];
+    fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), LongestBuilderError> {
+        match name {
+            "words" => {
+                ::core::result::Result::Err(LongestBuilderError::InvalidField {
+                    field: "words",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    LongestBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    fn call(&mut self) -> ::core::result::Result<&'w str, LongestBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.words.is_none() {
+            missing.push("words");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                LongestBuilderError::MissingFields(missing),
+            );
+        }
+        let words = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.words),
+        );
+        ::core::result::Result::Ok(longest(words))
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+enum LongestBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl LongestBuilderError {
+    #[doc(hidden)]
+    fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            LongestBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            LongestBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            LongestBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for LongestBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            LongestBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            LongestBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            LongestBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for LongestBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+struct LongestBuilderFieldMeta {
+    name: &'static str,
+    ty: &'static str,
+    required: bool,
+    doc: &'static str,
+}
+fn trim(text: &str) -> &str {
+    text.trim()
+}
+fn trim_builder<'__a0>() -> TrimBuilder<'__a0> {
+    <TrimBuilder<'__a0> as ::core::default::Default>::default()
+}
+#[derive(::core::fmt::Debug)]
+struct TrimBuilder<'__a0> {
+    text: ::core::option::Option<&'__a0 str>,
+}
+impl<'__a0> ::core::default::Default for TrimBuilder<'__a0> {
+    fn default() -> Self {
+        TrimBuilder {
+            text: ::core::option::Option::None,
+        }
+    }
+}
+impl<'__a0> TrimBuilder<'__a0> {
+    fn text(&mut self, val: &'__a0 str) -> &mut Self {
+        self.text = ::core::option::Option::Some(val);
+        self
+    }
+    fn text_ref(&self) -> ::core::option::Option<&&'__a0 str> {
+        ::core::option::Option::as_ref(&self.text)
+    }
+    fn is_text_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.text)
+    }
+    fn clear_text(&mut self) -> &mut Self {
+        self.text = ::core::option::Option::None;
+        self
+    }
+    fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.text.is_none() {
+            missing.push("text");
+        }
+        missing
+    }
+    const FIELDS: &'static [TrimBuilderFieldMeta] = &[
+        TrimBuilderFieldMeta {
+            name: "text",
+            ty: "&'__a0 str",
+            required: true,
+            doc: "",
+        },
+    ];
+    fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), TrimBuilderError> {
+        match name {
+            "text" => {
+                ::core::result::Result::Err(TrimBuilderError::InvalidField {
+                    field: "text",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    TrimBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    fn call(&mut self) -> ::core::result::Result<&'__a0 str, TrimBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.text.is_none() {
+            missing.push("text");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(TrimBuilderError::MissingFields(missing));
+        }
+        let text = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.text),
+        );
+        ::core::result::Result::Ok(trim(text))
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+enum TrimBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl TrimBuilderError {
+    #[doc(hidden)]
+    fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            TrimBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            TrimBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            TrimBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for TrimBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            TrimBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            TrimBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            TrimBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for TrimBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+struct TrimBuilderFieldMeta {
+    name: &'static str,
+    ty: &'static str,
+    required: bool,
+    doc: &'static str,
+}
+fn parse_all<T: std::str::FromStr>(inputs: Vec<&str>) -> Vec<T> {
+    inputs.into_iter().filter_map(|input| input.parse().ok()).collect()
+}
+fn parse_all_builder<'__a0>() -> ParseAllBuilder<'__a0> {
+    <ParseAllBuilder<'__a0> as ::core::default::Default>::default()
+}
+#[derive(::core::fmt::Debug)]
+struct ParseAllBuilder<'__a0> {
+    inputs: ::core::option::Option<Vec<&'__a0 str>>,
+}
+impl<'__a0> ::core::default::Default for ParseAllBuilder<'__a0> {
+    fn default() -> Self {
+        ParseAllBuilder {
+            inputs: ::core::option::Option::None,
+        }
+    }
+}
+impl<'__a0> ParseAllBuilder<'__a0> {
+    fn inputs(&mut self, val: Vec<&'__a0 str>) -> &mut Self {
+        self.inputs = ::core::option::Option::Some(val);
+        self
+    }
+    fn input(&mut self, val: &'__a0 str) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.inputs.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    fn extend_inputs<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = &'__a0 str>,
+    {
+        ::core::iter::Extend::extend(
+            self.inputs.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    fn inputs_ref(&self) -> ::core::option::Option<&Vec<&'__a0 str>> {
+        ::core::option::Option::as_ref(&self.inputs)
+    }
+    fn is_inputs_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.inputs)
+    }
+    fn clear_inputs(&mut self) -> &mut Self {
+        self.inputs = ::core::option::Option::None;
+        self
+    }
+    fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        missing
+    }
+    const FIELDS: &'static [ParseAllBuilderFieldMeta] = &[
+        ParseAllBuilderFieldMeta {
+            name: "inputs",
+            ty: "Vec<&'__a0 str>",
+            required: false,
+            doc: "",
+        },
+    ];
+    fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), ParseAllBuilderError> {
+        match name {
+            "inputs" => {
+                ::core::result::Result::Err(ParseAllBuilderError::InvalidField {
+                    field: "inputs",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    ParseAllBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    fn call<T: std::str::FromStr>(
+        &mut self,
+    ) -> ::core::result::Result<Vec<T>, ParseAllBuilderError> {
+        let inputs = match ::core::clone::Clone::clone(&self.inputs) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        ::core::result::Result::Ok(parse_all(inputs))
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+enum ParseAllBuilderError {
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl ParseAllBuilderError {
+    #[doc(hidden)]
+    fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            ParseAllBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            ParseAllBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for ParseAllBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ParseAllBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            ParseAllBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for ParseAllBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+struct ParseAllBuilderFieldMeta {
+    name: &'static str,
+    ty: &'static str,
+    required: bool,
+    doc: &'static str,
+}
+fn push(target: &mut Vec<u8>, value: u8) {
+    target.push(value);
+}
+fn push_builder<'__a0>() -> PushBuilder<'__a0> {
+    <PushBuilder<'__a0> as ::core::default::Default>::default()
+}
+#[derive(::core::fmt::Debug)]
+struct PushBuilder<'__a0> {
+    target: ::core::option::Option<&'__a0 mut Vec<u8>>,
+    value: ::core::option::Option<u8>,
+}
+impl<'__a0> ::core::default::Default for PushBuilder<'__a0> {
+    fn default() -> Self {
+        PushBuilder {
+            target: ::core::option::Option::None,
+            value: ::core::option::Option::None,
+        }
+    }
+}
+impl<'__a0> PushBuilder<'__a0> {
+    fn target(mut self, val: &'__a0 mut Vec<u8>) -> Self {
+        self.target = ::core::option::Option::Some(val);
+        self
+    }
+    fn value(mut self, val: u8) -> Self {
+        self.value = ::core::option::Option::Some(val);
+        self
+    }
+    fn target_ref(&self) -> ::core::option::Option<&&'__a0 mut Vec<u8>> {
+        ::core::option::Option::as_ref(&self.target)
+    }
+    fn is_target_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.target)
+    }
+    fn clear_target(mut self) -> Self {
+        self.target = ::core::option::Option::None;
+        self
+    }
+    fn value_ref(&self) -> ::core::option::Option<&u8> {
+        ::core::option::Option::as_ref(&self.value)
+    }
+    fn is_value_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.value)
+    }
+    fn clear_value(mut self) -> Self {
+        self.value = ::core::option::Option::None;
+        self
+    }
+    fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.target.is_none() {
+            missing.push("target");
+        }
+        if self.value.is_none() {
+            missing.push("value");
+        }
+        missing
+    }
+    const FIELDS: &'static [PushBuilderFieldMeta] = &[
+        PushBuilderFieldMeta {
+            name: "target",
+            ty: "&'__a0 mut Vec<u8>",
+            required: true,
+            doc: "",
+        },
+        PushBuilderFieldMeta {
+            name: "value",
+            ty: "u8",
+            required: true,
+            doc: "",
+        },
+    ];
+    fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), PushBuilderError>
+    where
+        for<'__a> u8: ::core::str::FromStr,
+        for<'__a> <u8 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "target" => {
+                ::core::result::Result::Err(PushBuilderError::InvalidField {
+                    field: "target",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "value" => {
+                self.value = ::core::option::Option::Some(
+                    match <u8 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(PushBuilderError::InvalidField {
+                                field: "value",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    PushBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    fn call(self) -> ::core::result::Result<(), PushBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.target.is_none() {
+            missing.push("target");
+        }
+        if self.value.is_none() {
+            missing.push("value");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(PushBuilderError::MissingFields(missing));
+        }
+        let target = ::core::option::Option::unwrap(self.target);
+        let value = ::core::option::Option::unwrap(self.value);
+        ::core::result::Result::Ok(push(target, value))
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+enum PushBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl PushBuilderError {
+    #[doc(hidden)]
+    fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            PushBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            PushBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            PushBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for PushBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            PushBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            PushBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            PushBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for PushBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+struct PushBuilderFieldMeta {
+    name: &'static str,
+    ty: &'static str,
+    required: bool,
+    doc: &'static str,
+}
+fn nothing() {}
+fn nothing_builder() -> NothingBuilder {
+    <NothingBuilder as ::core::default::Default>::default()
+}
+#[derive(::core::fmt::Debug)]
+struct NothingBuilder {}
+impl ::core::default::Default for NothingBuilder {
+    fn default() -> Self {
+        NothingBuilder {}
+    }
+}
+impl NothingBuilder {
+    fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        missing
+    }
+    const FIELDS: &'static [NothingBuilderFieldMeta] = &[];
+    fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), NothingBuilderError> {
+        match name {
+            _ => {
+                ::core::result::Result::Err(
+                    NothingBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    fn call(&mut self) -> ::core::result::Result<(), NothingBuilderError> {
+        ::core::result::Result::Ok(nothing())
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+enum NothingBuilderError {
+    UnknownField(::std::string::String),
+}
+impl NothingBuilderError {
+    #[doc(hidden)]
+    fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            NothingBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for NothingBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            NothingBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for NothingBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+struct NothingBuilderFieldMeta {
+    name: &'static str,
+    ty: &'static str,
+    required: bool,
+    doc: &'static str,
+}
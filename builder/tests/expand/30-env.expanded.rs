@@ -0,0 +1,476 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    current_dir: ::core::option::Option<String>,
+    priority: ::core::option::Option<u8>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+            priority: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn current_dir(&mut self, val: String) -> &mut Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn priority(&mut self, val: u8) -> &mut Self {
+        self.priority = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(&mut self) -> &mut Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+    pub fn priority_ref(&self) -> ::core::option::Option<&u8> {
+        ::core::option::Option::as_ref(&self.priority)
+    }
+    pub fn is_priority_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.priority)
+    }
+    pub fn clear_priority(&mut self) -> &mut Self {
+        self.priority = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none()
+            && ::std::env::var_os("BUILDER_TEST_EXECUTABLE").is_none()
+        {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "priority",
+            ty: "u8",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u8: ::core::str::FromStr,
+        for<'__a> <u8 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "current_dir" => {
+                self.current_dir = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "current_dir",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "priority" => {
+                self.priority = ::core::option::Option::Some(
+                    match <u8 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "priority",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn from_env() -> ::core::result::Result<Self, CommandBuilderError> {
+        let mut __builder = <Self as ::core::default::Default>::default();
+        __builder.executable = match ::std::env::var("BUILDER_TEST_EXECUTABLE") {
+            ::core::result::Result::Ok(raw) => {
+                match <String as ::core::str::FromStr>::from_str(&raw) {
+                    ::core::result::Result::Ok(value) => {
+                        ::core::option::Option::Some(value)
+                    }
+                    ::core::result::Result::Err(err) => {
+                        return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                            field: "executable",
+                            message: ::std::format!(
+                                "{}: {}", "BUILDER_TEST_EXECUTABLE", err
+                            ),
+                        });
+                    }
+                }
+            }
+            ::core::result::Result::Err(::std::env::VarError::NotPresent) => {
+                ::core::option::Option::None
+            }
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "executable",
+                    message: ::std::format!("{}: {}", "BUILDER_TEST_EXECUTABLE", err),
+                });
+            }
+        };
+        __builder.current_dir = match ::std::env::var("BUILDER_TEST_CURRENT_DIR") {
+            ::core::result::Result::Ok(raw) => {
+                match <String as ::core::str::FromStr>::from_str(&raw) {
+                    ::core::result::Result::Ok(value) => {
+                        ::core::option::Option::Some(value)
+                    }
+                    ::core::result::Result::Err(err) => {
+                        return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                            field: "current_dir",
+                            message: ::std::format!(
+                                "{}: {}", "BUILDER_TEST_CURRENT_DIR", err
+                            ),
+                        });
+                    }
+                }
+            }
+            ::core::result::Result::Err(::std::env::VarError::NotPresent) => {
+                ::core::option::Option::None
+            }
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "current_dir",
+                    message: ::std::format!("{}: {}", "BUILDER_TEST_CURRENT_DIR", err),
+                });
+            }
+        };
+        __builder.priority = match ::std::env::var("BUILDER_TEST_PRIORITY") {
+            ::core::result::Result::Ok(raw) => {
+                match <u8 as ::core::str::FromStr>::from_str(&raw) {
+                    ::core::result::Result::Ok(value) => {
+                        ::core::option::Option::Some(value)
+                    }
+                    ::core::result::Result::Err(err) => {
+                        return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                            field: "priority",
+                            message: ::std::format!(
+                                "{}: {}", "BUILDER_TEST_PRIORITY", err
+                            ),
+                        });
+                    }
+                }
+            }
+            ::core::result::Result::Err(::std::env::VarError::NotPresent) => {
+                ::core::option::Option::None
+            }
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "priority",
+                    message: ::std::format!("{}: {}", "BUILDER_TEST_PRIORITY", err),
+                });
+            }
+        };
+        ::core::result::Result::Ok(__builder)
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let __env_executable = match self.executable.is_some() {
+            true => ::core::option::Option::None,
+            false => {
+                match ::std::env::var("BUILDER_TEST_EXECUTABLE") {
+                    ::core::result::Result::Ok(raw) => {
+                        match <String as ::core::str::FromStr>::from_str(&raw) {
+                            ::core::result::Result::Ok(value) => {
+                                ::core::option::Option::Some(value)
+                            }
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "executable",
+                                    message: ::std::format!(
+                                        "{}: {}", "BUILDER_TEST_EXECUTABLE", err
+                                    ),
+                                });
+                            }
+                        }
+                    }
+                    ::core::result::Result::Err(::std::env::VarError::NotPresent) => {
+                        ::core::option::Option::None
+                    }
+                    ::core::result::Result::Err(err) => {
+                        return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                            field: "executable",
+                            message: ::std::format!(
+                                "{}: {}", "BUILDER_TEST_EXECUTABLE", err
+                            ),
+                        });
+                    }
+                }
+            }
+        };
+        let __env_current_dir = match self.current_dir.is_some() {
+            true => ::core::option::Option::None,
+            false => {
+                match ::std::env::var("BUILDER_TEST_CURRENT_DIR") {
+                    ::core::result::Result::Ok(raw) => {
+                        match <String as ::core::str::FromStr>::from_str(&raw) {
+                            ::core::result::Result::Ok(value) => {
+                                ::core::option::Option::Some(value)
+                            }
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "current_dir",
+                                    message: ::std::format!(
+                                        "{}: {}", "BUILDER_TEST_CURRENT_DIR", err
+                                    ),
+                                });
+                            }
+                        }
+                    }
+                    ::core::result::Result::Err(::std::env::VarError::NotPresent) => {
+                        ::core::option::Option::None
+                    }
+                    ::core::result::Result::Err(err) => {
+                        return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                            field: "current_dir",
+                            message: ::std::format!(
+                                "{}: {}", "BUILDER_TEST_CURRENT_DIR", err
+                            ),
+                        });
+                    }
+                }
+            }
+        };
+        let __env_priority = match self.priority.is_some() {
+            true => ::core::option::Option::None,
+            false => {
+                match ::std::env::var("BUILDER_TEST_PRIORITY") {
+                    ::core::result::Result::Ok(raw) => {
+                        match <u8 as ::core::str::FromStr>::from_str(&raw) {
+                            ::core::result::Result::Ok(value) => {
+                                ::core::option::Option::Some(value)
+                            }
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "priority",
+                                    message: ::std::format!(
+                                        "{}: {}", "BUILDER_TEST_PRIORITY", err
+                                    ),
+                                });
+                            }
+                        }
+                    }
+                    ::core::result::Result::Err(::std::env::VarError::NotPresent) => {
+                        ::core::option::Option::None
+                    }
+                    ::core::result::Result::Err(err) => {
+                        return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                            field: "priority",
+                            message: ::std::format!(
+                                "{}: {}", "BUILDER_TEST_PRIORITY", err
+                            ),
+                        });
+                    }
+                }
+            }
+        };
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() && __env_executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::option::Option::or(
+                ::core::clone::Clone::clone(&self.executable),
+                __env_executable,
+            ),
+        );
+        let current_dir = ::core::option::Option::or(
+            ::core::clone::Clone::clone(&self.current_dir),
+            __env_current_dir,
+        );
+        let priority = match ::core::option::Option::or(
+            ::core::clone::Clone::clone(&self.priority),
+            __env_priority,
+        ) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => 1,
+        };
+        let __built = Command {
+            executable,
+            current_dir,
+            priority,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            current_dir: value.current_dir,
+            priority: ::core::option::Option::Some(value.priority),
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> u8: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+            priority: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.priority),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
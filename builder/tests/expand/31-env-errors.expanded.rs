@@ -0,0 +1,6 @@
+::core::compile_error! {
+    "`env` cannot be combined with `skip`, `compute` or `sub_builder`"
+}
+::core::compile_error! {
+    "`env` is not supported by typestate builders"
+}
@@ -0,0 +1,759 @@
+#[derive(::core::fmt::Debug)]
+pub struct ConnectionBuilder {
+    host: ::core::option::Option<String>,
+    token: ::core::option::Option<String>,
+    username: ::core::option::Option<String>,
+    password: ::core::option::Option<String>,
+    log_file: ::core::option::Option<String>,
+    quiet: ::core::option::Option<bool>,
+}
+impl ::core::default::Default for ConnectionBuilder {
+    fn default() -> Self {
+        ConnectionBuilder {
+            host: ::core::option::Option::None,
+            token: ::core::option::Option::None,
+            username: ::core::option::Option::None,
+            password: ::core::option::Option::None,
+            log_file: ::core::option::Option::None,
+            quiet: ::core::option::Option::None,
+        }
+    }
+}
+impl Connection {
+    pub fn builder() -> ConnectionBuilder {
+        <ConnectionBuilder as ::core::default::Default>::default()
+    }
+}
+impl ConnectionBuilder {
+    pub fn host(&mut self, val: String) -> &mut Self {
+        self.host = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn token(&mut self, val: String) -> &mut Self {
+        self.token = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn username(&mut self, val: String) -> &mut Self {
+        self.username = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn password(&mut self, val: String) -> &mut Self {
+        self.password = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn log_file(&mut self, val: String) -> &mut Self {
+        self.log_file = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn quiet(&mut self, val: bool) -> &mut Self {
+        self.quiet = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn host_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.host)
+    }
+    pub fn is_host_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.host)
+    }
+    pub fn clear_host(&mut self) -> &mut Self {
+        self.host = ::core::option::Option::None;
+        self
+    }
+    pub fn token_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.token)
+    }
+    pub fn is_token_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.token)
+    }
+    pub fn clear_token(&mut self) -> &mut Self {
+        self.token = ::core::option::Option::None;
+        self
+    }
+    pub fn username_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.username)
+    }
+    pub fn is_username_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.username)
+    }
+    pub fn clear_username(&mut self) -> &mut Self {
+        self.username = ::core::option::Option::None;
+        self
+    }
+    pub fn password_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.password)
+    }
+    pub fn is_password_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.password)
+    }
+    pub fn clear_password(&mut self) -> &mut Self {
+        self.password = ::core::option::Option::None;
+        self
+    }
+    pub fn log_file_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.log_file)
+    }
+    pub fn is_log_file_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.log_file)
+    }
+    pub fn clear_log_file(&mut self) -> &mut Self {
+        self.log_file = ::core::option::Option::None;
+        self
+    }
+    pub fn quiet_ref(&self) -> ::core::option::Option<&bool> {
+        ::core::option::Option::as_ref(&self.quiet)
+    }
+    pub fn is_quiet_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.quiet)
+    }
+    pub fn clear_quiet(&mut self) -> &mut Self {
+        self.quiet = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.host.is_none() {
+            missing.push("host");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [ConnectionBuilderFieldMeta] = &[
+        ConnectionBuilderFieldMeta {
+            name: "host",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        ConnectionBuilderFieldMeta {
+            name: "token",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+        ConnectionBuilderFieldMeta {
+            name: "username",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+        ConnectionBuilderFieldMeta {
+            name: "password",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+        ConnectionBuilderFieldMeta {
+            name: "log_file",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+        ConnectionBuilderFieldMeta {
+            name: "quiet",
+            ty: "bool",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), ConnectionBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> bool: ::core::str::FromStr,
+        for<'__a> <bool as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "host" => {
+                self.host = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(ConnectionBuilderError::InvalidField {
+                                field: "host",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "token" => {
+                self.token = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(ConnectionBuilderError::InvalidField {
+                                field: "token",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "username" => {
+                self.username = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(ConnectionBuilderError::InvalidField {
+                                field: "username",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "password" => {
+                self.password = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(ConnectionBuilderError::InvalidField {
+                                field: "password",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "log_file" => {
+                self.log_file = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(ConnectionBuilderError::InvalidField {
+                                field: "log_file",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "quiet" => {
+                self.quiet = ::core::option::Option::Some(
+                    match <bool as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(ConnectionBuilderError::InvalidField {
+                                field: "quiet",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    ConnectionBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(
+        &mut self,
+    ) -> ::core::result::Result<Connection, ConnectionBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.host.is_none() {
+            missing.push("host");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                ConnectionBuilderError::MissingFields(missing),
+            );
+        }
+        {
+            let mut set = ::std::vec::Vec::new();
+            if self.token.is_some() {
+                set.push("token");
+            }
+            if self.username.is_some() {
+                set.push("username");
+            }
+            if set.len() > 1 {
+                return ::core::result::Result::Err(ConnectionBuilderError::GroupConflict {
+                    group: "auth",
+                    fields: set,
+                });
+            }
+            if set.is_empty() {
+                return ::core::result::Result::Err(ConnectionBuilderError::GroupUnset {
+                    group: "auth",
+                    fields: ::std::vec!["token", "username"],
+                });
+            }
+        }
+        {
+            let mut set = ::std::vec::Vec::new();
+            if self.log_file.is_some() {
+                set.push("log_file");
+            }
+            if self.quiet.is_some() {
+                set.push("quiet");
+            }
+            if set.len() > 1 {
+                return ::core::result::Result::Err(ConnectionBuilderError::GroupConflict {
+                    group: "output",
+                    fields: set,
+                });
+            }
+        }
+        if self.username.is_some() && !self.password.is_some() {
+            return ::core::result::Result::Err(ConnectionBuilderError::MissingRequired {
+                field: "username",
+                required: "password",
+            });
+        }
+        let host = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.host),
+        );
+        let token = ::core::clone::Clone::clone(&self.token);
+        let username = ::core::clone::Clone::clone(&self.username);
+        let password = ::core::clone::Clone::clone(&self.password);
+        let log_file = ::core::clone::Clone::clone(&self.log_file);
+        let quiet = match ::core::clone::Clone::clone(&self.quiet) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => false,
+        };
+        let __built = Connection {
+            host,
+            token,
+            username,
+            password,
+            log_file,
+            quiet,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Connection, ConnectionBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Connection> for ConnectionBuilder {
+    fn from(value: Connection) -> Self {
+        ConnectionBuilder {
+            host: ::core::option::Option::Some(value.host),
+            token: value.token,
+            username: value.username,
+            password: value.password,
+            log_file: value.log_file,
+            quiet: ::core::option::Option::Some(value.quiet),
+        }
+    }
+}
+impl Connection {
+    pub fn to_builder(&self) -> ConnectionBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> bool: ::core::clone::Clone,
+    {
+        ConnectionBuilder {
+            host: ::core::option::Option::Some(::core::clone::Clone::clone(&self.host)),
+            token: ::core::clone::Clone::clone(&self.token),
+            username: ::core::clone::Clone::clone(&self.username),
+            password: ::core::clone::Clone::clone(&self.password),
+            log_file: ::core::clone::Clone::clone(&self.log_file),
+            quiet: ::core::option::Option::Some(::core::clone::Clone::clone(&self.quiet)),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum ConnectionBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    GroupConflict { group: &'static str, fields: ::std::vec::Vec<&'static str> },
+    GroupUnset { group: &'static str, fields: ::std::vec::Vec<&'static str> },
+    MissingRequired { field: &'static str, required: &'static str },
+    UnknownField(::std::string::String),
+}
+impl ConnectionBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            ConnectionBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            ConnectionBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            ConnectionBuilderError::GroupConflict { .. } => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+            ConnectionBuilderError::GroupUnset { .. } => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+            ConnectionBuilderError::MissingRequired { .. } => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+            ConnectionBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for ConnectionBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ConnectionBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            ConnectionBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            ConnectionBuilderError::GroupConflict { group, fields } => {
+                ::core::write!(
+                    f, "at most one field of group `{}` can be set, got: {}", group,
+                    fields.join(", "),
+                )
+            }
+            ConnectionBuilderError::GroupUnset { group, fields } => {
+                ::core::write!(
+                    f, "exactly one field of group `{}` must be set: {}", group, fields
+                    .join(", "),
+                )
+            }
+            ConnectionBuilderError::MissingRequired { field, required } => {
+                ::core::write!(f, "field `{}` requires `{}` to be set", field, required)
+            }
+            ConnectionBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for ConnectionBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct ConnectionBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct RequestBuilder<__url = (), __text = (), __bytes = (), __encoding = ()> {
+    url: __url,
+    text: __text,
+    bytes: __bytes,
+    encoding: __encoding,
+    __marker: ::core::marker::PhantomData<fn() -> Request>,
+}
+impl Request {
+    pub fn builder() -> RequestBuilder {
+        RequestBuilder {
+            url: (),
+            text: (),
+            bytes: (),
+            encoding: (),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<
+    __url,
+    __text,
+    __bytes,
+    __encoding,
+> RequestBuilder<__url, __text, __bytes, __encoding> {
+    pub fn url(
+        self,
+        val: String,
+    ) -> RequestBuilder<(String,), __text, __bytes, __encoding> {
+        RequestBuilder {
+            url: (val,),
+            text: self.text,
+            bytes: self.bytes,
+            encoding: self.encoding,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn text(
+        self,
+        val: String,
+    ) -> RequestBuilder<__url, (String,), __bytes, __encoding> {
+        RequestBuilder {
+            url: self.url,
+            text: (val,),
+            bytes: self.bytes,
+            encoding: self.encoding,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn bytes(
+        self,
+        val: Vec<u8>,
+    ) -> RequestBuilder<__url, __text, (Vec<u8>,), __encoding> {
+        RequestBuilder {
+            url: self.url,
+            text: self.text,
+            bytes: (val,),
+            encoding: self.encoding,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn encoding(
+        self,
+        val: String,
+    ) -> RequestBuilder<__url, __text, __bytes, (String,)> {
+        RequestBuilder {
+            url: self.url,
+            text: self.text,
+            bytes: self.bytes,
+            encoding: (val,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl RequestBuilder {
+    pub const FIELDS: &'static [RequestBuilderFieldMeta] = &[
+        RequestBuilderFieldMeta {
+            name: "url",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        RequestBuilderFieldMeta {
+            name: "text",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+        RequestBuilderFieldMeta {
+            name: "bytes",
+            ty: "Option<Vec<u8>>",
+            required: false,
+            doc: "",
+        },
+        RequestBuilderFieldMeta {
+            name: "encoding",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`RequestBuilder::build()` requires field `url` to be set",
+        label = "call `.url(..)` before `.build()`"
+    )]
+    pub trait RequestBuilder_url_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> RequestBuilder_url_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait RequestBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> RequestBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> RequestBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`RequestBuilder::build()` allows at most one field of group `body` to be set",
+        label = "call at most one of `.text(..)`, `.bytes(..)`"
+    )]
+    pub trait RequestBuilder_body_at_most_one {}
+    impl<__T> RequestBuilder_body_at_most_one for ((__T,), ()) {}
+    impl<__T> RequestBuilder_body_at_most_one for ((), (__T,)) {}
+    impl RequestBuilder_body_at_most_one for ((), ()) {}
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`RequestBuilder::build()` requires field `text` to be set together with `encoding`",
+        label = "call `.text(..)` before `.build()`"
+    )]
+    pub trait RequestBuilder_encoding_requires_text {}
+    impl<__B> RequestBuilder_encoding_requires_text for ((), __B) {}
+    impl<__A, __B> RequestBuilder_encoding_requires_text for ((__A,), (__B,)) {}
+    #[allow(non_camel_case_types)]
+    impl<
+        __url,
+        __text,
+        __bytes,
+        __encoding,
+    > RequestBuilder<__url, __text, __bytes, __encoding>
+    where
+        __url: RequestBuilder_maybe_set<String>,
+        __text: RequestBuilder_maybe_set<String>,
+        __bytes: RequestBuilder_maybe_set<Vec<u8>>,
+        __encoding: RequestBuilder_maybe_set<String>,
+    {
+        pub fn url_ref(&self) -> ::core::option::Option<&String> {
+            RequestBuilder_maybe_set::<String>::value_ref(&self.url)
+        }
+        pub fn is_url_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.url_ref())
+        }
+        pub fn clear_url(self) -> RequestBuilder<(), __text, __bytes, __encoding> {
+            RequestBuilder {
+                url: (),
+                text: self.text,
+                bytes: self.bytes,
+                encoding: self.encoding,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn text_ref(&self) -> ::core::option::Option<&String> {
+            RequestBuilder_maybe_set::<String>::value_ref(&self.text)
+        }
+        pub fn is_text_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.text_ref())
+        }
+        pub fn clear_text(self) -> RequestBuilder<__url, (), __bytes, __encoding> {
+            RequestBuilder {
+                url: self.url,
+                text: (),
+                bytes: self.bytes,
+                encoding: self.encoding,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn bytes_ref(&self) -> ::core::option::Option<&Vec<u8>> {
+            RequestBuilder_maybe_set::<Vec<u8>>::value_ref(&self.bytes)
+        }
+        pub fn is_bytes_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.bytes_ref())
+        }
+        pub fn clear_bytes(self) -> RequestBuilder<__url, __text, (), __encoding> {
+            RequestBuilder {
+                url: self.url,
+                text: self.text,
+                bytes: (),
+                encoding: self.encoding,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn encoding_ref(&self) -> ::core::option::Option<&String> {
+            RequestBuilder_maybe_set::<String>::value_ref(&self.encoding)
+        }
+        pub fn is_encoding_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.encoding_ref())
+        }
+        pub fn clear_encoding(self) -> RequestBuilder<__url, __text, __bytes, ()> {
+            RequestBuilder {
+                url: self.url,
+                text: self.text,
+                bytes: self.bytes,
+                encoding: (),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_url_set() {
+                missing.push("url");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<
+        __url,
+        __text,
+        __bytes,
+        __encoding,
+    > RequestBuilder<__url, __text, __bytes, __encoding> {
+        pub fn build(self) -> Request
+        where
+            __url: RequestBuilder_url_is_set<String>,
+            __text: RequestBuilder_maybe_set<String>,
+            __bytes: RequestBuilder_maybe_set<Vec<u8>>,
+            __encoding: RequestBuilder_maybe_set<String>,
+            (__text, __bytes): RequestBuilder_body_at_most_one,
+            (__encoding, __text): RequestBuilder_encoding_requires_text,
+        {
+            let url = RequestBuilder_url_is_set::value(self.url);
+            let text = RequestBuilder_maybe_set::value(self.text);
+            let bytes = RequestBuilder_maybe_set::value(self.bytes);
+            let encoding = RequestBuilder_maybe_set::value(self.encoding);
+            Request {
+                url,
+                text,
+                bytes,
+                encoding,
+            }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct RequestBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,201 @@
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct RequestBuilder<__text = (), __bytes = (), __encoding = ()> {
+    text: __text,
+    bytes: __bytes,
+    encoding: __encoding,
+    __marker: ::core::marker::PhantomData<fn() -> Request>,
+}
+impl Request {
+    pub fn builder() -> RequestBuilder {
+        RequestBuilder {
+            text: (),
+            bytes: (),
+            encoding: (),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__text, __bytes, __encoding> RequestBuilder<__text, __bytes, __encoding> {
+    pub fn text(self, val: String) -> RequestBuilder<(String,), __bytes, __encoding> {
+        RequestBuilder {
+            text: (val,),
+            bytes: self.bytes,
+            encoding: self.encoding,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn bytes(self, val: Vec<u8>) -> RequestBuilder<__text, (Vec<u8>,), __encoding> {
+        RequestBuilder {
+            text: self.text,
+            bytes: (val,),
+            encoding: self.encoding,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn encoding(self, val: String) -> RequestBuilder<__text, __bytes, (String,)> {
+        RequestBuilder {
+            text: self.text,
+            bytes: self.bytes,
+            encoding: (val,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl RequestBuilder {
+    pub const FIELDS: &'static [RequestBuilderFieldMeta] = &[
+        RequestBuilderFieldMeta {
+            name: "text",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+        RequestBuilderFieldMeta {
+            name: "bytes",
+            ty: "Option<Vec<u8>>",
+            required: false,
+            doc: "",
+        },
+        RequestBuilderFieldMeta {
+            name: "encoding",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    pub trait RequestBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> RequestBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> RequestBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`RequestBuilder::build()` requires exactly one field of group `body` to be set",
+        label = "call exactly one of `.text(..)`, `.bytes(..)`"
+    )]
+    pub trait RequestBuilder_body_exactly_one {}
+    impl<__T> RequestBuilder_body_exactly_one for ((__T,), ()) {}
+    impl<__T> RequestBuilder_body_exactly_one for ((), (__T,)) {}
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`RequestBuilder::build()` requires field `text` to be set together with `encoding`",
+        label = "call `.text(..)` before `.build()`"
+    )]
+    pub trait RequestBuilder_encoding_requires_text {}
+    impl<__B> RequestBuilder_encoding_requires_text for ((), __B) {}
+    impl<__A, __B> RequestBuilder_encoding_requires_text for ((__A,), (__B,)) {}
+    #[allow(non_camel_case_types)]
+    impl<__text, __bytes, __encoding> RequestBuilder<__text, __bytes, __encoding>
+    where
+        __text: RequestBuilder_maybe_set<String>,
+        __bytes: RequestBuilder_maybe_set<Vec<u8>>,
+        __encoding: RequestBuilder_maybe_set<String>,
+    {
+        pub fn text_ref(&self) -> ::core::option::Option<&String> {
+            RequestBuilder_maybe_set::<String>::value_ref(&self.text)
+        }
+        pub fn is_text_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.text_ref())
+        }
+        pub fn clear_text(self) -> RequestBuilder<(), __bytes, __encoding> {
+            RequestBuilder {
+                text: (),
+                bytes: self.bytes,
+                encoding: self.encoding,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn bytes_ref(&self) -> ::core::option::Option<&Vec<u8>> {
+            RequestBuilder_maybe_set::<Vec<u8>>::value_ref(&self.bytes)
+        }
+        pub fn is_bytes_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.bytes_ref())
+        }
+        pub fn clear_bytes(self) -> RequestBuilder<__text, (), __encoding> {
+            RequestBuilder {
+                text: self.text,
+                bytes: (),
+                encoding: self.encoding,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn encoding_ref(&self) -> ::core::option::Option<&String> {
+            RequestBuilder_maybe_set::<String>::value_ref(&self.encoding)
+        }
+        pub fn is_encoding_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.encoding_ref())
+        }
+        pub fn clear_encoding(self) -> RequestBuilder<__text, __bytes, ()> {
+            RequestBuilder {
+                text: self.text,
+                bytes: self.bytes,
+                encoding: (),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__text, __bytes, __encoding> RequestBuilder<__text, __bytes, __encoding> {
+        pub fn build(self) -> Request
+        where
+            __text: RequestBuilder_maybe_set<String>,
+            __bytes: RequestBuilder_maybe_set<Vec<u8>>,
+            __encoding: RequestBuilder_maybe_set<String>,
+            (__text, __bytes): RequestBuilder_body_exactly_one,
+            (__encoding, __text): RequestBuilder_encoding_requires_text,
+        {
+            let text = RequestBuilder_maybe_set::value(self.text);
+            let bytes = RequestBuilder_maybe_set::value(self.bytes);
+            let encoding = RequestBuilder_maybe_set::value(self.encoding);
+            Request { text, bytes, encoding }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct RequestBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+::core::compile_error! {
+    "group `auth` needs `exactly_one` or `at_most_one`"
+}
+::core::compile_error! {
+    "group members must be optional fields with a setter"
+}
+::core::compile_error! {
+    "unknown field `pasword`"
+}
+::core::compile_error! {
+    "`exactly_one` and `at_most_one` require `group = \"...\"`"
+}
@@ -0,0 +1,954 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    args: ::core::option::Option<Vec<String>>,
+    current_dir: ::core::option::Option<String>,
+    tls: TlsBuilder,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            args: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+            tls: ::core::default::Default::default(),
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn args(&mut self, val: Vec<String>) -> &mut Self {
+        self.args = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn arg(&mut self, val: String) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_args<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn current_dir(&mut self, val: String) -> &mut Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn tls<__F>(&mut self, configure: __F) -> &mut Self
+    where
+        __F: ::core::ops::FnOnce(&mut TlsBuilder) -> &mut TlsBuilder,
+    {
+        configure(&mut self.tls);
+        self
+    }
+    pub fn tls_mut(&mut self) -> &mut TlsBuilder {
+        &mut self.tls
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.args)
+    }
+    pub fn is_args_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.args)
+    }
+    pub fn clear_args(&mut self) -> &mut Self {
+        self.args = ::core::option::Option::None;
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(&mut self) -> &mut Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+    pub fn tls_ref(&self) -> &TlsBuilder {
+        &self.tls
+    }
+    pub fn clear_tls(&mut self) -> &mut Self {
+        self.tls = ::core::default::Default::default();
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "tls",
+            ty: "Tls",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "args" => {
+                ::core::iter::Extend::extend(
+                    self.args.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "args",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "current_dir" => {
+                self.current_dir = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "current_dir",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "tls" => {
+                ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "tls",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let __sub_tls = self.tls.__try_build();
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            let mut nested = ::std::vec::Vec::new();
+            if let ::core::result::Result::Err(err) = &__sub_tls {
+                nested.extend(err.__nested_errors("tls"));
+            }
+            if !nested.is_empty() {
+                let mut errors: ::std::vec::Vec<_> = missing
+                    .iter()
+                    .map(|field| (
+                        ::std::string::ToString::to_string(field),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect();
+                errors.extend(nested);
+                return ::core::result::Result::Err(CommandBuilderError::Nested(errors));
+            }
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let args = match ::core::clone::Clone::clone(&self.args) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let current_dir = ::core::clone::Clone::clone(&self.current_dir);
+        let tls = match __sub_tls {
+            ::core::result::Result::Ok(__value) => __value,
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(
+                    CommandBuilderError::Nested(err.__nested_errors("tls")),
+                );
+            }
+        };
+        let __built = Command {
+            executable,
+            args,
+            current_dir,
+            tls,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            args: ::core::option::Option::Some(value.args),
+            current_dir: value.current_dir,
+            tls: ::core::convert::From::from(value.tls),
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Tls: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            args: ::core::option::Option::Some(::core::clone::Clone::clone(&self.args)),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+            tls: ::core::convert::From::from(::core::clone::Clone::clone(&self.tls)),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+    Nested(::std::vec::Vec<(::std::string::String, ::std::string::String)>),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+            CommandBuilderError::Nested(errors) => {
+                errors
+                    .iter()
+                    .map(|(inner, message)| (
+                        path(inner),
+                        ::core::clone::Clone::clone(message),
+                    ))
+                    .collect()
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+            CommandBuilderError::Nested(errors) => {
+                for (i, (path, message)) in errors.iter().enumerate() {
+                    if i > 0 {
+                        ::core::write!(f, ", ")?;
+                    }
+                    ::core::write!(f, "{}: {}", path, message)?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct TlsBuilder {
+    cert: ::core::option::Option<String>,
+}
+impl ::core::default::Default for TlsBuilder {
+    fn default() -> Self {
+        TlsBuilder {
+            cert: ::core::option::Option::None,
+        }
+    }
+}
+impl Tls {
+    pub fn builder() -> TlsBuilder {
+        <TlsBuilder as ::core::default::Default>::default()
+    }
+}
+impl TlsBuilder {
+    pub fn cert(&mut self, val: String) -> &mut Self {
+        self.cert = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn cert_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.cert)
+    }
+    pub fn is_cert_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.cert)
+    }
+    pub fn clear_cert(&mut self) -> &mut Self {
+        self.cert = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.cert.is_none() {
+            missing.push("cert");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [TlsBuilderFieldMeta] = &[
+        TlsBuilderFieldMeta {
+            name: "cert",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), TlsBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "cert" => {
+                self.cert = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(TlsBuilderError::InvalidField {
+                                field: "cert",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    TlsBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Tls, TlsBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.cert.is_none() {
+            missing.push("cert");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(TlsBuilderError::MissingFields(missing));
+        }
+        let cert = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.cert),
+        );
+        let __built = Tls { cert };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(&mut self) -> ::core::result::Result<Tls, TlsBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Tls> for TlsBuilder {
+    fn from(value: Tls) -> Self {
+        TlsBuilder {
+            cert: ::core::option::Option::Some(value.cert),
+        }
+    }
+}
+impl Tls {
+    pub fn to_builder(&self) -> TlsBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+    {
+        TlsBuilder {
+            cert: ::core::option::Option::Some(::core::clone::Clone::clone(&self.cert)),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum TlsBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl TlsBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            TlsBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            TlsBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            TlsBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for TlsBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            TlsBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            TlsBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            TlsBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for TlsBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct TlsBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct JobBuilder {
+    name: ::core::option::Option<String>,
+    retries: ::core::option::Option<u32>,
+}
+impl ::core::default::Default for JobBuilder {
+    fn default() -> Self {
+        JobBuilder {
+            name: ::core::option::Option::None,
+            retries: ::core::option::Option::None,
+        }
+    }
+}
+impl Job {
+    pub fn builder() -> JobBuilder {
+        <JobBuilder as ::core::default::Default>::default()
+    }
+}
+impl JobBuilder {
+    pub fn name(mut self, val: String) -> Self {
+        self.name = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn retries(mut self, val: u32) -> Self {
+        self.retries = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn name_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.name)
+    }
+    pub fn is_name_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.name)
+    }
+    pub fn clear_name(mut self) -> Self {
+        self.name = ::core::option::Option::None;
+        self
+    }
+    pub fn retries_ref(&self) -> ::core::option::Option<&u32> {
+        ::core::option::Option::as_ref(&self.retries)
+    }
+    pub fn is_retries_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.retries)
+    }
+    pub fn clear_retries(mut self) -> Self {
+        self.retries = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [JobBuilderFieldMeta] = &[
+        JobBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        JobBuilderFieldMeta {
+            name: "retries",
+            ty: "Option<u32>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), JobBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u32: ::core::str::FromStr,
+        for<'__a> <u32 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "name" => {
+                self.name = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(JobBuilderError::InvalidField {
+                                field: "name",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "retries" => {
+                self.retries = ::core::option::Option::Some(
+                    match <u32 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(JobBuilderError::InvalidField {
+                                field: "retries",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    JobBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(self) -> ::core::result::Result<Job, JobBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(JobBuilderError::MissingFields(missing));
+        }
+        let name = ::core::option::Option::unwrap(self.name);
+        let retries = self.retries;
+        let __built = Job { name, retries };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(self) -> ::core::result::Result<Job, JobBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Job> for JobBuilder {
+    fn from(value: Job) -> Self {
+        JobBuilder {
+            name: ::core::option::Option::Some(value.name),
+            retries: value.retries,
+        }
+    }
+}
+impl Job {
+    pub fn to_builder(&self) -> JobBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> u32: ::core::clone::Clone,
+    {
+        JobBuilder {
+            name: ::core::option::Option::Some(::core::clone::Clone::clone(&self.name)),
+            retries: ::core::clone::Clone::clone(&self.retries),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum JobBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl JobBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            JobBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            JobBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            JobBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for JobBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            JobBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            JobBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            JobBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for JobBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct JobBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct RequestBuilder<__url = ()> {
+    url: __url,
+    method: ::core::option::Option<String>,
+    __marker: ::core::marker::PhantomData<fn() -> Request>,
+}
+impl Request {
+    pub fn builder() -> RequestBuilder {
+        RequestBuilder {
+            url: (),
+            method: ::core::option::Option::None,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__url> RequestBuilder<__url> {
+    pub fn url(self, val: String) -> RequestBuilder<(String,)> {
+        RequestBuilder {
+            url: (val,),
+            method: self.method,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+    pub fn method(mut self, val: String) -> Self {
+        self.method = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn method_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.method)
+    }
+    pub fn is_method_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.method)
+    }
+    pub fn clear_method(mut self) -> Self {
+        self.method = ::core::option::Option::None;
+        self
+    }
+}
+impl RequestBuilder {
+    pub const FIELDS: &'static [RequestBuilderFieldMeta] = &[
+        RequestBuilderFieldMeta {
+            name: "url",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        RequestBuilderFieldMeta {
+            name: "method",
+            ty: "Option<String>",
+            required: false,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Request> for RequestBuilder<(String,)> {
+    fn from(value: Request) -> Self {
+        RequestBuilder {
+            url: (value.url,),
+            method: value.method,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Request {
+    pub fn to_builder(&self) -> RequestBuilder<(String,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+    {
+        RequestBuilder {
+            url: (::core::clone::Clone::clone(&self.url),),
+            method: ::core::clone::Clone::clone(&self.method),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`RequestBuilder::build()` requires field `url` to be set",
+        label = "call `.url(..)` before `.build()`"
+    )]
+    pub trait RequestBuilder_url_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> RequestBuilder_url_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait RequestBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> RequestBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> RequestBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__url> RequestBuilder<__url>
+    where
+        __url: RequestBuilder_maybe_set<String>,
+    {
+        pub fn url_ref(&self) -> ::core::option::Option<&String> {
+            RequestBuilder_maybe_set::<String>::value_ref(&self.url)
+        }
+        pub fn is_url_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.url_ref())
+        }
+        pub fn clear_url(self) -> RequestBuilder<()> {
+            RequestBuilder {
+                url: (),
+                method: self.method,
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_url_set() {
+                missing.push("url");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__url> RequestBuilder<__url> {
+        pub fn build(self) -> Request
+        where
+            __url: RequestBuilder_url_is_set<String>,
+        {
+            let url = RequestBuilder_url_is_set::value(self.url);
+            let method = self.method;
+            Request { url, method }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct RequestBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
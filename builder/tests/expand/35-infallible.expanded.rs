@@ -0,0 +1,863 @@
+#[derive(::core::fmt::Debug)]
+pub struct OptionsBuilder {
+    verbose: ::core::option::Option<bool>,
+    features: ::core::option::Option<Vec<String>>,
+    jobs: ::core::option::Option<u32>,
+}
+impl ::core::default::Default for OptionsBuilder {
+    fn default() -> Self {
+        OptionsBuilder {
+            verbose: ::core::option::Option::None,
+            features: ::core::option::Option::None,
+            jobs: ::core::option::Option::None,
+        }
+    }
+}
+impl Options {
+    pub fn builder() -> OptionsBuilder {
+        <OptionsBuilder as ::core::default::Default>::default()
+    }
+}
+impl OptionsBuilder {
+    pub fn verbose(&mut self, val: bool) -> &mut Self {
+        self.verbose = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn features(&mut self, val: Vec<String>) -> &mut Self {
+        self.features = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn feature(&mut self, val: String) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.features.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_features<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.features.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn jobs(&mut self, val: u32) -> &mut Self {
+        self.jobs = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn verbose_ref(&self) -> ::core::option::Option<&bool> {
+        ::core::option::Option::as_ref(&self.verbose)
+    }
+    pub fn is_verbose_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.verbose)
+    }
+    pub fn clear_verbose(&mut self) -> &mut Self {
+        self.verbose = ::core::option::Option::None;
+        self
+    }
+    pub fn features_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.features)
+    }
+    pub fn is_features_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.features)
+    }
+    pub fn clear_features(&mut self) -> &mut Self {
+        self.features = ::core::option::Option::None;
+        self
+    }
+    pub fn jobs_ref(&self) -> ::core::option::Option<&u32> {
+        ::core::option::Option::as_ref(&self.jobs)
+    }
+    pub fn is_jobs_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.jobs)
+    }
+    pub fn clear_jobs(&mut self) -> &mut Self {
+        self.jobs = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        missing
+    }
+    pub const FIELDS: &'static [OptionsBuilderFieldMeta] = &[
+        OptionsBuilderFieldMeta {
+            name: "verbose",
+            ty: "Option<bool>",
+            required: false,
+            doc: "",
+        },
+        OptionsBuilderFieldMeta {
+            name: "features",
+            ty: "Vec<String>",
+            required: false,
+            doc: "",
+        },
+        OptionsBuilderFieldMeta {
+            name: "jobs",
+            ty: "u32",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), OptionsBuilderError>
+    where
+        for<'__a> bool: ::core::str::FromStr,
+        for<'__a> <bool as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u32: ::core::str::FromStr,
+        for<'__a> <u32 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "verbose" => {
+                self.verbose = ::core::option::Option::Some(
+                    match <bool as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(OptionsBuilderError::InvalidField {
+                                field: "verbose",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "features" => {
+                ::core::iter::Extend::extend(
+                    self.features.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(OptionsBuilderError::InvalidField {
+                                    field: "features",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "jobs" => {
+                self.jobs = ::core::option::Option::Some(
+                    match <u32 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(OptionsBuilderError::InvalidField {
+                                field: "jobs",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    OptionsBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> Options {
+        let verbose = ::core::clone::Clone::clone(&self.verbose);
+        let features = match ::core::clone::Clone::clone(&self.features) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let jobs = match ::core::clone::Clone::clone(&self.jobs) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => 4,
+        };
+        Options { verbose, features, jobs }
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Options, OptionsBuilderError> {
+        ::core::result::Result::Ok(self.build())
+    }
+}
+impl ::core::convert::From<Options> for OptionsBuilder {
+    fn from(value: Options) -> Self {
+        OptionsBuilder {
+            verbose: value.verbose,
+            features: ::core::option::Option::Some(value.features),
+            jobs: ::core::option::Option::Some(value.jobs),
+        }
+    }
+}
+impl Options {
+    pub fn to_builder(&self) -> OptionsBuilder
+    where
+        for<'__a> bool: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> u32: ::core::clone::Clone,
+    {
+        OptionsBuilder {
+            verbose: ::core::clone::Clone::clone(&self.verbose),
+            features: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.features),
+            ),
+            jobs: ::core::option::Option::Some(::core::clone::Clone::clone(&self.jobs)),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum OptionsBuilderError {
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl OptionsBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            OptionsBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            OptionsBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for OptionsBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            OptionsBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            OptionsBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for OptionsBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct OptionsBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct StableBuilder {
+    verbose: ::core::option::Option<bool>,
+}
+impl ::core::default::Default for StableBuilder {
+    fn default() -> Self {
+        StableBuilder {
+            verbose: ::core::option::Option::None,
+        }
+    }
+}
+impl Stable {
+    pub fn builder() -> StableBuilder {
+        <StableBuilder as ::core::default::Default>::default()
+    }
+}
+impl StableBuilder {
+    pub fn verbose(&mut self, val: bool) -> &mut Self {
+        self.verbose = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn verbose_ref(&self) -> ::core::option::Option<&bool> {
+        ::core::option::Option::as_ref(&self.verbose)
+    }
+    pub fn is_verbose_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.verbose)
+    }
+    pub fn clear_verbose(&mut self) -> &mut Self {
+        self.verbose = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        missing
+    }
+    pub const FIELDS: &'static [StableBuilderFieldMeta] = &[
+        StableBuilderFieldMeta {
+            name: "verbose",
+            ty: "Option<bool>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), StableBuilderError>
+    where
+        for<'__a> bool: ::core::str::FromStr,
+        for<'__a> <bool as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "verbose" => {
+                self.verbose = ::core::option::Option::Some(
+                    match <bool as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(StableBuilderError::InvalidField {
+                                field: "verbose",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    StableBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Stable, StableBuilderError> {
+        let verbose = ::core::clone::Clone::clone(&self.verbose);
+        let __built = Stable { verbose };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(&mut self) -> ::core::result::Result<Stable, StableBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Stable> for StableBuilder {
+    fn from(value: Stable) -> Self {
+        StableBuilder {
+            verbose: value.verbose,
+        }
+    }
+}
+impl Stable {
+    pub fn to_builder(&self) -> StableBuilder
+    where
+        for<'__a> bool: ::core::clone::Clone,
+    {
+        StableBuilder {
+            verbose: ::core::clone::Clone::clone(&self.verbose),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum StableBuilderError {
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl StableBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            StableBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            StableBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for StableBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            StableBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            StableBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for StableBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct StableBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct RequestBuilder<__url = ()> {
+    url: __url,
+    __marker: ::core::marker::PhantomData<fn() -> Request>,
+}
+impl Request {
+    pub fn builder() -> RequestBuilder {
+        RequestBuilder {
+            url: (),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__url> RequestBuilder<__url> {
+    pub fn url(self, val: String) -> RequestBuilder<(String,)> {
+        RequestBuilder {
+            url: (val,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl RequestBuilder {
+    pub const FIELDS: &'static [RequestBuilderFieldMeta] = &[
+        RequestBuilderFieldMeta {
+            name: "url",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Request> for RequestBuilder<(String,)> {
+    fn from(value: Request) -> Self {
+        RequestBuilder {
+            url: (value.url,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Request {
+    pub fn to_builder(&self) -> RequestBuilder<(String,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+    {
+        RequestBuilder {
+            url: (::core::clone::Clone::clone(&self.url),),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`RequestBuilder::build()` requires field `url` to be set",
+        label = "call `.url(..)` before `.build()`"
+    )]
+    pub trait RequestBuilder_url_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> RequestBuilder_url_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait RequestBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> RequestBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> RequestBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__url> RequestBuilder<__url>
+    where
+        __url: RequestBuilder_maybe_set<String>,
+    {
+        pub fn url_ref(&self) -> ::core::option::Option<&String> {
+            RequestBuilder_maybe_set::<String>::value_ref(&self.url)
+        }
+        pub fn is_url_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.url_ref())
+        }
+        pub fn clear_url(self) -> RequestBuilder<()> {
+            RequestBuilder {
+                url: (),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_url_set() {
+                missing.push("url");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__url> RequestBuilder<__url> {
+        pub fn build(self) -> ::core::result::Result<Request, RequestBuilderError>
+        where
+            __url: RequestBuilder_url_is_set<String>,
+        {
+            let url = RequestBuilder_url_is_set::value(self.url);
+            let __built = Request { url };
+            ::core::result::Result::Ok(__built)
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum RequestBuilderError {}
+impl RequestBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        match *self {}
+    }
+}
+impl ::core::fmt::Display for RequestBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match *self {}
+    }
+}
+impl ::std::error::Error for RequestBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct RequestBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    options: OptionsBuilder,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            options: ::core::default::Default::default(),
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn options<__F>(&mut self, configure: __F) -> &mut Self
+    where
+        __F: ::core::ops::FnOnce(&mut OptionsBuilder) -> &mut OptionsBuilder,
+    {
+        configure(&mut self.options);
+        self
+    }
+    pub fn options_mut(&mut self) -> &mut OptionsBuilder {
+        &mut self.options
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn options_ref(&self) -> &OptionsBuilder {
+        &self.options
+    }
+    pub fn clear_options(&mut self) -> &mut Self {
+        self.options = ::core::default::Default::default();
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "options",
+            ty: "Options",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "options" => {
+                ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "options",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let __sub_options = self.options.__try_build();
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            let mut nested = ::std::vec::Vec::new();
+            if let ::core::result::Result::Err(err) = &__sub_options {
+                nested.extend(err.__nested_errors("options"));
+            }
+            if !nested.is_empty() {
+                let mut errors: ::std::vec::Vec<_> = missing
+                    .iter()
+                    .map(|field| (
+                        ::std::string::ToString::to_string(field),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect();
+                errors.extend(nested);
+                return ::core::result::Result::Err(CommandBuilderError::Nested(errors));
+            }
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let options = match __sub_options {
+            ::core::result::Result::Ok(__value) => __value,
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(
+                    CommandBuilderError::Nested(err.__nested_errors("options")),
+                );
+            }
+        };
+        let __built = Command { executable, options };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            options: ::core::convert::From::from(value.options),
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Options: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            options: ::core::convert::From::from(
+                ::core::clone::Clone::clone(&self.options),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+    Nested(::std::vec::Vec<(::std::string::String, ::std::string::String)>),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+            CommandBuilderError::Nested(errors) => {
+                errors
+                    .iter()
+                    .map(|(inner, message)| (
+                        path(inner),
+                        ::core::clone::Clone::clone(message),
+                    ))
+                    .collect()
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+            CommandBuilderError::Nested(errors) => {
+                for (i, (path, message)) in errors.iter().enumerate() {
+                    if i > 0 {
+                        ::core::write!(f, ", ")?;
+                    }
+                    ::core::write!(f, "{}: {}", path, message)?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
@@ -0,0 +1,384 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    current_dir: ::core::option::Option<String>,
+    priority: ::core::option::Option<u8>,
+    log_file: ::core::option::Option<<MaybePath as ::core::iter::IntoIterator>::Item>,
+    limit: ::core::option::Option<custom::Option<u32>>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+            priority: ::core::option::Option::None,
+            log_file: ::core::option::Option::None,
+            limit: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn current_dir(&mut self, val: String) -> &mut Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn priority(&mut self, val: u8) -> &mut Self {
+        self.priority = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn log_file(
+        &mut self,
+        val: <MaybePath as ::core::iter::IntoIterator>::Item,
+    ) -> &mut Self {
+        self.log_file = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn limit(&mut self, val: custom::Option<u32>) -> &mut Self {
+        self.limit = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(&mut self) -> &mut Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+    pub fn priority_ref(&self) -> ::core::option::Option<&u8> {
+        ::core::option::Option::as_ref(&self.priority)
+    }
+    pub fn is_priority_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.priority)
+    }
+    pub fn clear_priority(&mut self) -> &mut Self {
+        self.priority = ::core::option::Option::None;
+        self
+    }
+    pub fn log_file_ref(
+        &self,
+    ) -> ::core::option::Option<&<MaybePath as ::core::iter::IntoIterator>::Item> {
+        ::core::option::Option::as_ref(&self.log_file)
+    }
+    pub fn is_log_file_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.log_file)
+    }
+    pub fn clear_log_file(&mut self) -> &mut Self {
+        self.log_file = ::core::option::Option::None;
+        self
+    }
+    pub fn limit_ref(&self) -> ::core::option::Option<&custom::Option<u32>> {
+        ::core::option::Option::as_ref(&self.limit)
+    }
+    pub fn is_limit_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.limit)
+    }
+    pub fn clear_limit(&mut self) -> &mut Self {
+        self.limit = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if self.limit.is_none() {
+            missing.push("limit");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "std::option::Option<String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "priority",
+            ty: "::core::option::Option<u8>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "log_file",
+            ty: "MaybePath",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "limit",
+            ty: "custom::Option<u32>",
+            required: true,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u8: ::core::str::FromStr,
+        for<'__a> <u8 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> <MaybePath as ::core::iter::IntoIterator>::Item: ::core::str::FromStr,
+        for<'__a> <<MaybePath as ::core::iter::IntoIterator>::Item as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> custom::Option<u32>: ::core::str::FromStr,
+        for<'__a> <custom::Option<
+            u32,
+        > as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "current_dir" => {
+                self.current_dir = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "current_dir",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "priority" => {
+                self.priority = ::core::option::Option::Some(
+                    match <u8 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "priority",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "log_file" => {
+                self.log_file = ::core::option::Option::Some(
+                    match <<MaybePath as ::core::iter::IntoIterator>::Item as ::core::str::FromStr>::from_str(
+                        value,
+                    ) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "log_file",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "limit" => {
+                self.limit = ::core::option::Option::Some(
+                    match <custom::Option<
+                        u32,
+                    > as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "limit",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if self.limit.is_none() {
+            missing.push("limit");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let current_dir = ::core::clone::Clone::clone(&self.current_dir);
+        let priority = ::core::clone::Clone::clone(&self.priority);
+        let log_file = ::core::clone::Clone::clone(&self.log_file);
+        let limit = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.limit),
+        );
+        let __built = Command {
+            executable,
+            current_dir,
+            priority,
+            log_file,
+            limit,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            current_dir: value.current_dir,
+            priority: value.priority,
+            log_file: value.log_file,
+            limit: ::core::option::Option::Some(value.limit),
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> u8: ::core::clone::Clone,
+        for<'__a> <MaybePath as ::core::iter::IntoIterator>::Item: ::core::clone::Clone,
+        for<'__a> custom::Option<u32>: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+            priority: ::core::clone::Clone::clone(&self.priority),
+            log_file: ::core::clone::Clone::clone(&self.log_file),
+            limit: ::core::option::Option::Some(::core::clone::Clone::clone(&self.limit)),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
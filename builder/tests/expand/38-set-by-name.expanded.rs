@@ -0,0 +1,709 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<String>,
+    args: ::core::option::Option<Vec<String>>,
+    env: ::core::option::Option<HashMap<String, String>>,
+    timeout: ::core::option::Option<u64>,
+    grace: ::core::option::Option<Duration>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            args: ::core::option::Option::None,
+            env: ::core::option::Option::None,
+            timeout: ::core::option::Option::None,
+            grace: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub fn builder() -> CommandBuilder {
+        <CommandBuilder as ::core::default::Default>::default()
+    }
+}
+impl CommandBuilder {
+    pub fn executable(&mut self, val: String) -> &mut Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn args(&mut self, val: Vec<String>) -> &mut Self {
+        self.args = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn arg(&mut self, val: String) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once(val),
+        );
+        self
+    }
+    pub fn extend_args<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = String>,
+    {
+        ::core::iter::Extend::extend(
+            self.args.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn env(&mut self, key: String, value: String) -> &mut Self {
+        ::core::iter::Extend::extend(
+            self.env.get_or_insert_with(::core::default::Default::default),
+            ::core::iter::once((key, value)),
+        );
+        self
+    }
+    pub fn extend_env<__I>(&mut self, iter: __I) -> &mut Self
+    where
+        __I: ::core::iter::IntoIterator<Item = (String, String)>,
+    {
+        ::core::iter::Extend::extend(
+            self.env.get_or_insert_with(::core::default::Default::default),
+            iter,
+        );
+        self
+    }
+    pub fn timeout(&mut self, val: u64) -> &mut Self {
+        self.timeout = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn grace(&mut self, val: Duration) -> &mut Self {
+        self.grace = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(&mut self) -> &mut Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn args_ref(&self) -> ::core::option::Option<&Vec<String>> {
+        ::core::option::Option::as_ref(&self.args)
+    }
+    pub fn is_args_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.args)
+    }
+    pub fn clear_args(&mut self) -> &mut Self {
+        self.args = ::core::option::Option::None;
+        self
+    }
+    pub fn env_ref(&self) -> ::core::option::Option<&HashMap<String, String>> {
+        ::core::option::Option::as_ref(&self.env)
+    }
+    pub fn is_env_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.env)
+    }
+    pub fn clear_env(&mut self) -> &mut Self {
+        self.env = ::core::option::Option::None;
+        self
+    }
+    pub fn timeout_ref(&self) -> ::core::option::Option<&u64> {
+        ::core::option::Option::as_ref(&self.timeout)
+    }
+    pub fn is_timeout_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.timeout)
+    }
+    pub fn clear_timeout(&mut self) -> &mut Self {
+        self.timeout = ::core::option::Option::None;
+        self
+    }
+    pub fn grace_ref(&self) -> ::core::option::Option<&Duration> {
+        ::core::option::Option::as_ref(&self.grace)
+    }
+    pub fn is_grace_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.grace)
+    }
+    pub fn clear_grace(&mut self) -> &mut Self {
+        self.grace = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "String",
+            required: true,
+            doc: "Program to run.",
+        },
+        CommandBuilderFieldMeta {
+            name: "args",
+            ty: "Vec<String>",
+            required: false,
+            doc: "Arguments, in order.",
+        },
+        CommandBuilderFieldMeta {
+            name: "env",
+            ty: "HashMap<String, String>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "timeout",
+            ty: "Option<u64>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "grace",
+            ty: "Option<Duration>",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+        for<'__a> u64: ::core::str::FromStr,
+        for<'__a> <u64 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                self.executable = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "executable",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "args" => {
+                ::core::iter::Extend::extend(
+                    self.args.get_or_insert_with(::core::default::Default::default),
+                    ::core::iter::once(
+                        match <String as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(err) => {
+                                return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                    field: "args",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        },
+                    ),
+                );
+                ::core::result::Result::Ok(())
+            }
+            "env" => {
+                ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "env",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "timeout" => {
+                self.timeout = ::core::option::Option::Some(
+                    match <u64 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "timeout",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "grace" => {
+                ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "grace",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.executable),
+        );
+        let args = match ::core::clone::Clone::clone(&self.args) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let env = match ::core::clone::Clone::clone(&self.env) {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => ::core::default::Default::default(),
+        };
+        let timeout = ::core::clone::Clone::clone(&self.timeout);
+        let grace = ::core::clone::Clone::clone(&self.grace);
+        let __built = Command {
+            executable,
+            args,
+            env,
+            timeout,
+            grace,
+        };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(
+        &mut self,
+    ) -> ::core::result::Result<Command, CommandBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            args: ::core::option::Option::Some(value.args),
+            env: ::core::option::Option::Some(value.env),
+            timeout: value.timeout,
+            grace: value.grace,
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Vec<String>: ::core::clone::Clone,
+        for<'__a> HashMap<String, String>: ::core::clone::Clone,
+        for<'__a> u64: ::core::clone::Clone,
+        for<'__a> Duration: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            args: ::core::option::Option::Some(::core::clone::Clone::clone(&self.args)),
+            env: ::core::option::Option::Some(::core::clone::Clone::clone(&self.env)),
+            timeout: ::core::clone::Clone::clone(&self.timeout),
+            grace: ::core::clone::Clone::clone(&self.grace),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct EntryBuilder {
+    r#type: ::core::option::Option<String>,
+}
+impl ::core::default::Default for EntryBuilder {
+    fn default() -> Self {
+        EntryBuilder {
+            r#type: ::core::option::Option::None,
+        }
+    }
+}
+impl Entry {
+    pub fn builder() -> EntryBuilder {
+        <EntryBuilder as ::core::default::Default>::default()
+    }
+}
+impl EntryBuilder {
+    pub fn r#type(&mut self, val: String) -> &mut Self {
+        self.r#type = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn type_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.r#type)
+    }
+    pub fn is_type_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.r#type)
+    }
+    pub fn clear_type(&mut self) -> &mut Self {
+        self.r#type = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.r#type.is_none() {
+            missing.push("type");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [EntryBuilderFieldMeta] = &[
+        EntryBuilderFieldMeta {
+            name: "type",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), EntryBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "type" => {
+                self.r#type = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(EntryBuilderError::InvalidField {
+                                field: "type",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    EntryBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(&mut self) -> ::core::result::Result<Entry, EntryBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.r#type.is_none() {
+            missing.push("type");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                EntryBuilderError::MissingFields(missing),
+            );
+        }
+        let r#type = ::core::option::Option::unwrap(
+            ::core::clone::Clone::clone(&self.r#type),
+        );
+        let __built = Entry { r#type };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(&mut self) -> ::core::result::Result<Entry, EntryBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Entry> for EntryBuilder {
+    fn from(value: Entry) -> Self {
+        EntryBuilder {
+            r#type: ::core::option::Option::Some(value.r#type),
+        }
+    }
+}
+impl Entry {
+    pub fn to_builder(&self) -> EntryBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+    {
+        EntryBuilder {
+            r#type: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.r#type),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum EntryBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl EntryBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            EntryBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            EntryBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            EntryBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for EntryBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            EntryBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            EntryBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            EntryBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for EntryBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct EntryBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[allow(non_camel_case_types)]
+#[derive(::core::fmt::Debug)]
+pub struct RequestBuilder<__url = ()> {
+    url: __url,
+    __marker: ::core::marker::PhantomData<fn() -> Request>,
+}
+impl Request {
+    pub fn builder() -> RequestBuilder {
+        RequestBuilder {
+            url: (),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+#[allow(non_camel_case_types)]
+impl<__url> RequestBuilder<__url> {
+    pub fn url(self, val: String) -> RequestBuilder<(String,)> {
+        RequestBuilder {
+            url: (val,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl RequestBuilder {
+    pub const FIELDS: &'static [RequestBuilderFieldMeta] = &[
+        RequestBuilderFieldMeta {
+            name: "url",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+    ];
+}
+impl ::core::convert::From<Request> for RequestBuilder<(String,)> {
+    fn from(value: Request) -> Self {
+        RequestBuilder {
+            url: (value.url,),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl Request {
+    pub fn to_builder(&self) -> RequestBuilder<(String,)>
+    where
+        for<'__a> String: ::core::clone::Clone,
+    {
+        RequestBuilder {
+            url: (::core::clone::Clone::clone(&self.url),),
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+const _: () = {
+    #[allow(non_camel_case_types)]
+    #[diagnostic::on_unimplemented(
+        message = "`RequestBuilder::build()` requires field `url` to be set",
+        label = "call `.url(..)` before `.build()`"
+    )]
+    pub trait RequestBuilder_url_is_set<T> {
+        fn value(self) -> T;
+    }
+    impl<T> RequestBuilder_url_is_set<T> for (T,) {
+        fn value(self) -> T {
+            self.0
+        }
+    }
+    #[allow(non_camel_case_types)]
+    pub trait RequestBuilder_maybe_set<T> {
+        fn value(self) -> ::core::option::Option<T>;
+        fn value_ref(&self) -> ::core::option::Option<&T>;
+    }
+    impl<T> RequestBuilder_maybe_set<T> for () {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::None
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::None
+        }
+    }
+    impl<T> RequestBuilder_maybe_set<T> for (T,) {
+        fn value(self) -> ::core::option::Option<T> {
+            ::core::option::Option::Some(self.0)
+        }
+        fn value_ref(&self) -> ::core::option::Option<&T> {
+            ::core::option::Option::Some(&self.0)
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__url> RequestBuilder<__url>
+    where
+        __url: RequestBuilder_maybe_set<String>,
+    {
+        pub fn url_ref(&self) -> ::core::option::Option<&String> {
+            RequestBuilder_maybe_set::<String>::value_ref(&self.url)
+        }
+        pub fn is_url_set(&self) -> bool {
+            ::core::option::Option::is_some(&self.url_ref())
+        }
+        pub fn clear_url(self) -> RequestBuilder<()> {
+            RequestBuilder {
+                url: (),
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+        pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+            let mut missing = ::std::vec::Vec::new();
+            if !self.is_url_set() {
+                missing.push("url");
+            }
+            missing
+        }
+    }
+    #[allow(non_camel_case_types)]
+    impl<__url> RequestBuilder<__url> {
+        pub fn build(self) -> Request
+        where
+            __url: RequestBuilder_url_is_set<String>,
+        {
+            let url = RequestBuilder_url_is_set::value(self.url);
+            Request { url }
+        }
+    }
+};
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct RequestBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
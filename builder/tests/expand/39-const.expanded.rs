@@ -0,0 +1,549 @@
+#[derive(::core::fmt::Debug)]
+pub struct CommandBuilder {
+    executable: ::core::option::Option<&'static str>,
+    current_dir: ::core::option::Option<&'static str>,
+    timeout_secs: ::core::option::Option<u64>,
+}
+impl ::core::default::Default for CommandBuilder {
+    fn default() -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+            timeout_secs: ::core::option::Option::None,
+        }
+    }
+}
+impl Command {
+    pub const fn builder() -> CommandBuilder {
+        CommandBuilder {
+            executable: ::core::option::Option::None,
+            current_dir: ::core::option::Option::None,
+            timeout_secs: ::core::option::Option::None,
+        }
+    }
+}
+impl CommandBuilder {
+    pub const fn executable(mut self, val: &'static str) -> Self {
+        self.executable = ::core::option::Option::Some(val);
+        self
+    }
+    pub const fn current_dir(mut self, val: &'static str) -> Self {
+        self.current_dir = ::core::option::Option::Some(val);
+        self
+    }
+    pub const fn timeout_secs(mut self, val: u64) -> Self {
+        self.timeout_secs = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn executable_ref(&self) -> ::core::option::Option<&&'static str> {
+        ::core::option::Option::as_ref(&self.executable)
+    }
+    pub fn is_executable_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.executable)
+    }
+    pub fn clear_executable(mut self) -> Self {
+        self.executable = ::core::option::Option::None;
+        self
+    }
+    pub fn current_dir_ref(&self) -> ::core::option::Option<&&'static str> {
+        ::core::option::Option::as_ref(&self.current_dir)
+    }
+    pub fn is_current_dir_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.current_dir)
+    }
+    pub fn clear_current_dir(mut self) -> Self {
+        self.current_dir = ::core::option::Option::None;
+        self
+    }
+    pub fn timeout_secs_ref(&self) -> ::core::option::Option<&u64> {
+        ::core::option::Option::as_ref(&self.timeout_secs)
+    }
+    pub fn is_timeout_secs_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.timeout_secs)
+    }
+    pub fn clear_timeout_secs(mut self) -> Self {
+        self.timeout_secs = ::core::option::Option::None;
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [CommandBuilderFieldMeta] = &[
+        CommandBuilderFieldMeta {
+            name: "executable",
+            ty: "&'static str",
+            required: true,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "current_dir",
+            ty: "Option<&'static str>",
+            required: false,
+            doc: "",
+        },
+        CommandBuilderFieldMeta {
+            name: "timeout_secs",
+            ty: "u64",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), CommandBuilderError>
+    where
+        for<'__a> u64: ::core::str::FromStr,
+        for<'__a> <u64 as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "executable" => {
+                ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "executable",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "current_dir" => {
+                ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                    field: "current_dir",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            "timeout_secs" => {
+                self.timeout_secs = ::core::option::Option::Some(
+                    match <u64 as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(CommandBuilderError::InvalidField {
+                                field: "timeout_secs",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    CommandBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub const fn build(self) -> Command {
+        let executable = match self.executable {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => {
+                ::core::panic!(
+                    "`CommandBuilder::build()`: missing required field `executable`"
+                )
+            }
+        };
+        let current_dir = self.current_dir;
+        let timeout_secs = match self.timeout_secs {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => 30,
+        };
+        let timeout_ms = timeout_secs * 1000;
+        Command {
+            executable,
+            current_dir,
+            timeout_secs,
+            timeout_ms,
+        }
+    }
+    #[doc(hidden)]
+    pub fn __try_build(self) -> ::core::result::Result<Command, CommandBuilderError> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.executable.is_none() {
+            missing.push("executable");
+        }
+        if !missing.is_empty() {
+            return ::core::result::Result::Err(
+                CommandBuilderError::MissingFields(missing),
+            );
+        }
+        let executable = ::core::option::Option::unwrap(self.executable);
+        let current_dir = self.current_dir;
+        let timeout_secs = match self.timeout_secs {
+            ::core::option::Option::Some(__value) => __value,
+            ::core::option::Option::None => 30,
+        };
+        let timeout_ms = timeout_secs * 1000;
+        ::core::result::Result::Ok(Command {
+            executable,
+            current_dir,
+            timeout_secs,
+            timeout_ms,
+        })
+    }
+}
+impl ::core::convert::From<Command> for CommandBuilder {
+    fn from(value: Command) -> Self {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(value.executable),
+            current_dir: value.current_dir,
+            timeout_secs: ::core::option::Option::Some(value.timeout_secs),
+        }
+    }
+}
+impl Command {
+    pub fn to_builder(&self) -> CommandBuilder
+    where
+        for<'__a> &'static str: ::core::clone::Clone,
+        for<'__a> &'static str: ::core::clone::Clone,
+        for<'__a> u64: ::core::clone::Clone,
+    {
+        CommandBuilder {
+            executable: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.executable),
+            ),
+            current_dir: ::core::clone::Clone::clone(&self.current_dir),
+            timeout_secs: ::core::option::Option::Some(
+                ::core::clone::Clone::clone(&self.timeout_secs),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum CommandBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+}
+impl CommandBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            CommandBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            CommandBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CommandBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            CommandBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            CommandBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct CommandBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
+#[derive(::core::fmt::Debug)]
+pub struct JobBuilder {
+    name: ::core::option::Option<String>,
+    command: CommandBuilder,
+}
+impl ::core::default::Default for JobBuilder {
+    fn default() -> Self {
+        JobBuilder {
+            name: ::core::option::Option::None,
+            command: ::core::default::Default::default(),
+        }
+    }
+}
+impl Job {
+    pub fn builder() -> JobBuilder {
+        <JobBuilder as ::core::default::Default>::default()
+    }
+}
+impl JobBuilder {
+    pub fn name(mut self, val: String) -> Self {
+        self.name = ::core::option::Option::Some(val);
+        self
+    }
+    pub fn command<__F>(mut self, configure: __F) -> Self
+    where
+        __F: ::core::ops::FnOnce(&mut CommandBuilder) -> &mut CommandBuilder,
+    {
+        configure(&mut self.command);
+        self
+    }
+    pub fn command_mut(&mut self) -> &mut CommandBuilder {
+        &mut self.command
+    }
+    pub fn name_ref(&self) -> ::core::option::Option<&String> {
+        ::core::option::Option::as_ref(&self.name)
+    }
+    pub fn is_name_set(&self) -> bool {
+        ::core::option::Option::is_some(&self.name)
+    }
+    pub fn clear_name(mut self) -> Self {
+        self.name = ::core::option::Option::None;
+        self
+    }
+    pub fn command_ref(&self) -> &CommandBuilder {
+        &self.command
+    }
+    pub fn clear_command(mut self) -> Self {
+        self.command = ::core::default::Default::default();
+        self
+    }
+    pub fn missing_fields(&self) -> ::std::vec::Vec<&'static str> {
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        missing
+    }
+    pub const FIELDS: &'static [JobBuilderFieldMeta] = &[
+        JobBuilderFieldMeta {
+            name: "name",
+            ty: "String",
+            required: true,
+            doc: "",
+        },
+        JobBuilderFieldMeta {
+            name: "command",
+            ty: "Command",
+            required: false,
+            doc: "",
+        },
+    ];
+    pub fn set_by_name(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> ::core::result::Result<(), JobBuilderError>
+    where
+        for<'__a> String: ::core::str::FromStr,
+        for<'__a> <String as ::core::str::FromStr>::Err: ::core::fmt::Display,
+    {
+        match name {
+            "name" => {
+                self.name = ::core::option::Option::Some(
+                    match <String as ::core::str::FromStr>::from_str(value) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(JobBuilderError::InvalidField {
+                                field: "name",
+                                message: ::std::string::ToString::to_string(&err),
+                            });
+                        }
+                    },
+                );
+                ::core::result::Result::Ok(())
+            }
+            "command" => {
+                ::core::result::Result::Err(JobBuilderError::InvalidField {
+                    field: "command",
+                    message: ::std::string::ToString::to_string("cannot be set by name"),
+                })
+            }
+            _ => {
+                ::core::result::Result::Err(
+                    JobBuilderError::UnknownField(
+                        ::std::string::ToString::to_string(name),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn build(self) -> ::core::result::Result<Job, JobBuilderError> {
+        let __sub_command = {
+            let mut __sub_builder = self.command;
+            __sub_builder.__try_build()
+        };
+        let mut missing = ::std::vec::Vec::new();
+        if self.name.is_none() {
+            missing.push("name");
+        }
+        if !missing.is_empty() {
+            let mut nested = ::std::vec::Vec::new();
+            if let ::core::result::Result::Err(err) = &__sub_command {
+                nested.extend(err.__nested_errors("command"));
+            }
+            if !nested.is_empty() {
+                let mut errors: ::std::vec::Vec<_> = missing
+                    .iter()
+                    .map(|field| (
+                        ::std::string::ToString::to_string(field),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect();
+                errors.extend(nested);
+                return ::core::result::Result::Err(JobBuilderError::Nested(errors));
+            }
+            return ::core::result::Result::Err(JobBuilderError::MissingFields(missing));
+        }
+        let name = ::core::option::Option::unwrap(self.name);
+        let command = match __sub_command {
+            ::core::result::Result::Ok(__value) => __value,
+            ::core::result::Result::Err(err) => {
+                return ::core::result::Result::Err(
+                    JobBuilderError::Nested(err.__nested_errors("command")),
+                );
+            }
+        };
+        let __built = Job { name, command };
+        ::core::result::Result::Ok(__built)
+    }
+    #[doc(hidden)]
+    pub fn __try_build(self) -> ::core::result::Result<Job, JobBuilderError> {
+        self.build()
+    }
+}
+impl ::core::convert::From<Job> for JobBuilder {
+    fn from(value: Job) -> Self {
+        JobBuilder {
+            name: ::core::option::Option::Some(value.name),
+            command: ::core::convert::From::from(value.command),
+        }
+    }
+}
+impl Job {
+    pub fn to_builder(&self) -> JobBuilder
+    where
+        for<'__a> String: ::core::clone::Clone,
+        for<'__a> Command: ::core::clone::Clone,
+    {
+        JobBuilder {
+            name: ::core::option::Option::Some(::core::clone::Clone::clone(&self.name)),
+            command: ::core::convert::From::from(
+                ::core::clone::Clone::clone(&self.command),
+            ),
+        }
+    }
+}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+#[non_exhaustive]
+pub enum JobBuilderError {
+    MissingFields(::std::vec::Vec<&'static str>),
+    InvalidField { field: &'static str, message: ::std::string::String },
+    UnknownField(::std::string::String),
+    Nested(::std::vec::Vec<(::std::string::String, ::std::string::String)>),
+}
+impl JobBuilderError {
+    #[doc(hidden)]
+    pub fn __nested_errors(
+        &self,
+        field: &str,
+    ) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+        let path = |inner: &str| match inner.is_empty() {
+            true => ::std::string::ToString::to_string(field),
+            false => ::std::format!("{}.{}", field, inner),
+        };
+        match self {
+            JobBuilderError::MissingFields(fields) => {
+                fields
+                    .iter()
+                    .map(|inner| (
+                        path(inner),
+                        ::std::string::ToString::to_string("missing"),
+                    ))
+                    .collect()
+            }
+            JobBuilderError::InvalidField { field: inner, message } => {
+                ::std::vec![(path(inner), ::core::clone::Clone::clone(message))]
+            }
+            JobBuilderError::UnknownField(_) => {
+                ::std::vec![(path(""), ::std::string::ToString::to_string(self))]
+            }
+            JobBuilderError::Nested(errors) => {
+                errors
+                    .iter()
+                    .map(|(inner, message)| (
+                        path(inner),
+                        ::core::clone::Clone::clone(message),
+                    ))
+                    .collect()
+            }
+        }
+    }
+}
+impl ::core::fmt::Display for JobBuilderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            JobBuilderError::MissingFields(fields) => {
+                ::core::write!(f, "missing required fields: {}", fields.join(", "))
+            }
+            JobBuilderError::InvalidField { field, message } => {
+                ::core::write!(f, "invalid value for field `{}`: {}", field, message)
+            }
+            JobBuilderError::UnknownField(name) => {
+                ::core::write!(f, "unknown field `{}`", name)
+            }
+            JobBuilderError::Nested(errors) => {
+                for (i, (path, message)) in errors.iter().enumerate() {
+                    if i > 0 {
+                        ::core::write!(f, ", ")?;
+                    }
+                    ::core::write!(f, "{}: {}", path, message)?;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+    }
+}
+impl ::std::error::Error for JobBuilderError {}
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct JobBuilderFieldMeta {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub required: bool,
+    pub doc: &'static str,
+}
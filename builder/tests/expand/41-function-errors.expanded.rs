@@ -0,0 +1,6 @@
+::core::compile_error! {
+    "#[builder] does not support `impl Trait` parameters, use a named generic"
+}
+::core::compile_error! {
+    "`&mut` parameters require `pattern = \"owned\"`"
+}
@@ -7,9 +7,40 @@ fn tests() {
     t.pass("tests/04-call-build.rs");
     t.pass("tests/05-method-chaining.rs");
     t.pass("tests/06-optional-field.rs");
-    // Currently failing
-    //t.pass("tests/double-usage.rs");
-    //t.pass("tests/07-repeated-field.rs");
-    //t.compile_fail("tests/08-unrecognized-attribute.rs");
-    //t.pass("tests/09-redefined-prelude-types.rs");
+    t.pass("tests/10-typestate.rs");
+    t.compile_fail("tests/11-typestate-missing-field.rs");
+    t.pass("tests/12-structured-error.rs");
+    t.pass("tests/double-usage.rs");
+    t.pass("tests/09-redefined-prelude-types.rs");
+    t.pass("tests/07-repeated-field.rs");
+    t.compile_fail("tests/08-unrecognized-attribute.rs");
+    t.pass("tests/13-hygiene.rs");
+    t.pass("tests/14-each-collections.rs");
+    t.pass("tests/15-default.rs");
+    t.pass("tests/16-generics.rs");
+    t.compile_fail("tests/17-not-a-struct.rs");
+    t.pass("tests/18-non-path-types.rs");
+    t.pass("tests/19-pattern.rs");
+    t.compile_fail("tests/20-pattern-errors.rs");
+    t.pass("tests/21-setter-into.rs");
+    t.pass("tests/22-validate.rs");
+    t.pass("tests/23-to-builder.rs");
+    t.pass("tests/24-skip-compute.rs");
+    t.compile_fail("tests/25-skip-compute-errors.rs");
+    t.pass("tests/26-builder-naming.rs");
+    t.pass("tests/27-sub-builder.rs");
+    t.compile_fail("tests/28-sub-builder-errors.rs");
+    t.pass("tests/29-function.rs");
+    t.pass("tests/30-env.rs");
+    t.compile_fail("tests/31-env-errors.rs");
+    t.pass("tests/32-groups.rs");
+    t.compile_fail("tests/33-groups-errors.rs");
+    t.pass("tests/34-accessors.rs");
+    t.pass("tests/35-infallible.rs");
+    t.pass("tests/36-field-types.rs");
+    t.pass("tests/37-patch.rs");
+    t.pass("tests/38-set-by-name.rs");
+    t.pass("tests/39-const.rs");
+    t.compile_fail("tests/40-const-errors.rs");
+    t.compile_fail("tests/41-function-errors.rs");
 }
@@ -0,0 +1,136 @@
+use syn::{GenericArgument, PathArguments, Type, TypePath};
+
+// Вид типа поля, важный для derive-макросов, с аргументами типа
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldType<'a> {
+    Option(&'a Type),
+    Vec(&'a Type),
+    VecDeque(&'a Type),
+    // HashSet<T>, BTreeSet<T>
+    Set(&'a Type),
+    // HashMap<K, V>, BTreeMap<K, V>
+    Map(&'a Type, &'a Type),
+    Box(&'a Type),
+    Rc(&'a Type),
+    Arc(&'a Type),
+    PhantomData(&'a Type),
+    // Cow<'a, B>: лайфтайм пропускается, остаётся B
+    Cow(&'a Type),
+    Other,
+}
+
+// Известные типы и модули, через которые они доступны. Тип узнаётся по
+// голому имени (Option<T>) или по полному пути из std/core/alloc
+// (std::option::Option<T>, ::core::option::Option<T>)
+const KNOWN_TYPES: &[(&str, &[&str])] = &[
+    ("Option", &["std::option", "core::option"]),
+    ("Vec", &["std::vec", "alloc::vec"]),
+    (
+        "VecDeque",
+        &[
+            "std::collections",
+            "std::collections::vec_deque",
+            "alloc::collections",
+            "alloc::collections::vec_deque",
+        ],
+    ),
+    ("HashSet", &["std::collections", "std::collections::hash_set"]),
+    (
+        "BTreeSet",
+        &[
+            "std::collections",
+            "std::collections::btree_set",
+            "alloc::collections",
+            "alloc::collections::btree_set",
+        ],
+    ),
+    ("HashMap", &["std::collections", "std::collections::hash_map"]),
+    (
+        "BTreeMap",
+        &[
+            "std::collections",
+            "std::collections::btree_map",
+            "alloc::collections",
+            "alloc::collections::btree_map",
+        ],
+    ),
+    ("Box", &["std::boxed", "alloc::boxed"]),
+    ("Rc", &["std::rc", "alloc::rc"]),
+    ("Arc", &["std::sync", "alloc::sync"]),
+    ("PhantomData", &["std::marker", "core::marker"]),
+    ("Cow", &["std::borrow", "alloc::borrow"]),
+];
+
+// Имя известного типа, если путь ведёт к нему
+fn known_name(path: &syn::Path) -> Option<&'static str> {
+    let last = path.segments.last()?;
+    let (name, modules) = KNOWN_TYPES.iter().find(|(name, _)| last.ident == name)?;
+    if path.leading_colon.is_none() && path.segments.len() == 1 {
+        return Some(name);
+    }
+    let module = path
+        .segments
+        .iter()
+        .take(path.segments.len() - 1)
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+    modules.contains(&module.as_str()).then_some(name)
+}
+
+// Аргументы-типы последнего сегмента, без лайфтаймов и констант
+fn type_args(path: &syn::Path) -> Vec<&Type> {
+    match path.segments.last().map(|segment| &segment.arguments) {
+        Some(PathArguments::AngleBracketed(args)) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub fn classify(ty: &Type) -> FieldType<'_> {
+    let path = match ty {
+        // типы из macro_rules приходят в невидимых скобках
+        Type::Group(group) => return classify(&group.elem),
+        Type::Paren(paren) => return classify(&paren.elem),
+        Type::Path(TypePath { qself: None, path }) => path,
+        _ => return FieldType::Other,
+    };
+    let Some(name) = known_name(path) else {
+        return FieldType::Other;
+    };
+    let args = type_args(path);
+    match (name, args.as_slice()) {
+        ("Option", [inner]) => FieldType::Option(inner),
+        ("Vec", [inner, ..]) => FieldType::Vec(inner),
+        ("VecDeque", [inner, ..]) => FieldType::VecDeque(inner),
+        ("HashSet" | "BTreeSet", [inner, ..]) => FieldType::Set(inner),
+        ("HashMap" | "BTreeMap", [key, value, ..]) => FieldType::Map(key, value),
+        ("Box", [inner, ..]) => FieldType::Box(inner),
+        ("Rc", [inner, ..]) => FieldType::Rc(inner),
+        ("Arc", [inner, ..]) => FieldType::Arc(inner),
+        ("PhantomData", [inner]) => FieldType::PhantomData(inner),
+        ("Cow", [inner]) => FieldType::Cow(inner),
+        _ => FieldType::Other,
+    }
+}
+
+// T из Option<T>
+pub fn option_inner(ty: &Type) -> Option<&Type> {
+    match classify(ty) {
+        FieldType::Option(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+pub fn is_phantom_data(ty: &Type) -> bool {
+    matches!(classify(ty), FieldType::PhantomData(_))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+// Классификация типов, записанных по-разному
+
+use crate::{classify, option_inner, FieldType};
+use syn::{parse_quote, Type};
+
+#[test]
+fn option_paths() {
+    let inner: Type = parse_quote! { String };
+    let paths: [Type; 5] = [
+        parse_quote! { Option<String> },
+        parse_quote! { std::option::Option<String> },
+        parse_quote! { ::std::option::Option<String> },
+        parse_quote! { core::option::Option<String> },
+        parse_quote! { ::core::option::Option<String> },
+    ];
+    for ty in &paths {
+        assert_eq!(option_inner(ty), Some(&inner));
+    }
+}
+
+#[test]
+fn unknown_paths() {
+    let paths: [Type; 5] = [
+        parse_quote! { my::Option<String> },
+        parse_quote! { std::vec::Option<String> },
+        parse_quote! { <Vec<u8> as IntoIterator>::Item },
+        parse_quote! { &'a Option<u8> },
+        parse_quote! { Option },
+    ];
+    for ty in &paths {
+        assert_eq!(classify(ty), FieldType::Other);
+    }
+}
+
+#[test]
+fn containers() {
+    let ty: Type = parse_quote! { std::collections::HashMap<String, u32> };
+    assert!(matches!(classify(&ty), FieldType::Map(..)));
+    let ty: Type = parse_quote! { alloc::collections::btree_map::BTreeMap<u8, u8> };
+    assert!(matches!(classify(&ty), FieldType::Map(..)));
+    let ty: Type = parse_quote! { Cow<'a, str> };
+    assert_eq!(classify(&ty), FieldType::Cow(&parse_quote! { str }));
+    let ty: Type = parse_quote! { ::core::marker::PhantomData<T> };
+    assert_eq!(classify(&ty), FieldType::PhantomData(&parse_quote! { T }));
+    let ty: Type = parse_quote! { std::sync::Arc<Mutex<u8>> };
+    assert!(matches!(classify(&ty), FieldType::Arc(_)));
+    let ty: Type = parse_quote! { Vec<u8, Global> };
+    assert_eq!(classify(&ty), FieldType::Vec(&parse_quote! { u8 }));
+    let ty: Type = parse_quote! { std::collections::VecDeque<u8> };
+    assert_eq!(classify(&ty), FieldType::VecDeque(&parse_quote! { u8 }));
+    let ty: Type = parse_quote! { HashSet<String, RandomState> };
+    assert_eq!(classify(&ty), FieldType::Set(&parse_quote! { String }));
+    let ty: Type = parse_quote! { alloc::collections::btree_set::BTreeSet<u8> };
+    assert_eq!(classify(&ty), FieldType::Set(&parse_quote! { u8 }));
+    let ty: Type = parse_quote! { IndexMap<String, u8> };
+    assert_eq!(classify(&ty), FieldType::Other);
+}